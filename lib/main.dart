@@ -5,21 +5,12 @@ import 'package:tonestep/src/rust/api/note_utils.dart' as note_utils;
 import 'package:auto_size_text/auto_size_text.dart';
 import 'package:tonestep/src/rust/frb_generated.dart';
 import 'package:tonestep/components/theme.dart';
-import 'package:flutter/services.dart';
 
+// The voice answers are compiled into the Rust library, see the
+// embedded-assets feature in rust/Cargo.toml
 Future<void> main() async {
   await RustLib.init();
   runApp(ToneStep());
-
-  List<Uint8List> wavDataList = [];
-
-  for (int i = 1; i <= 12; i++) {
-    ByteData data = await rootBundle.load('rust/resources/$i.wav');
-    Uint8List bytes = data.buffer.asUint8List();
-    wavDataList.add(bytes);
-  }
-
-  api.initWavFilesFromBytes(wavData: wavDataList);
 }
 
 class NotesProvider {
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'notes.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `cmp`, `eq`, `fmt`, `fmt`, `from_str`, `from`, `hash`, `partial_cmp`
+
+/// Comma separated degrees, e.g. `1,b3+,5,1050c`.
+Future<Set<Degree>> parseDegrees({required String s}) =>
+    RustLib.instance.api.crateApiDegreesParseDegrees(s: s);
+
+Future<Set<Degree>> degreesFromNotes({required Set<Note> notes}) =>
+    RustLib.instance.api.crateApiDegreesDegreesFromNotes(notes: notes);
+
+/// All the degrees of an equal division of the octave, 24 gives quarter tones.
+Future<List<Degree>> edoDegrees({required int divisions}) =>
+    RustLib.instance.api.crateApiDegreesEdoDegrees(divisions: divisions);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Degree>>
+abstract class Degree implements RustOpaqueInterface {
+  int get cents;
+
+  /// Cents above `note_below`.
+  Future<int> centsAboveNote();
+
+  /// Rounds `cents` to whole cents.
+  static Future<Degree> fromCents({required double cents}) =>
+      RustLib.instance.api.crateApiDegreesDegreeFromCents(cents: cents);
+
+  factory Degree({required int cents}) =>
+      RustLib.instance.api.crateApiDegreesDegreeNew(cents: cents);
+
+  /// The `Note` this degree falls on, `None` for microtonal degrees.
+  Future<Note?> note();
+
+  /// The nearest `Note` at or below this degree.
+  Future<Note> noteBelow();
+}
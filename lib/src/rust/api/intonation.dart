@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'notes.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Streams readings to Dart, oldest first, about 90 a second while
+/// something is sung.
+///
+/// Readings are collected while `start_listening` is active and an exercise
+/// is playing, so the drone keeps sounding as the reference. The stream is
+/// fed until `stop_listening` or `stop_playing`, or until another stream is
+/// opened.
+Stream<IntonationReading> intonationStream() =>
+    RustLib.instance.api.crateApiIntonationIntonationStream();
+
+/// Where a sung or played pitch sits against the drone.
+class IntonationReading {
+  final double frequency;
+  /// The nearest degree above the current root, in any octave.
+  final Note note;
+  /// Offset from `note`, negative when flat.
+  final double cents;
+  /// How clearly pitched the input is, from 0 to 1.
+  final double confidence;
+
+  const IntonationReading({
+    required this.frequency,
+    required this.note,
+    required this.cents,
+    required this.confidence,
+  });
+
+  @override
+  int get hashCode =>
+      frequency.hashCode ^
+      note.hashCode ^
+      cents.hashCode ^
+      confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntonationReading &&
+          runtimeType == other.runtimeType &&
+          frequency == other.frequency &&
+          note == other.note &&
+          cents == other.cents &&
+          confidence == other.confidence;
+}
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `hash`
+
+Future<void> setLayerGain({required Layer layer, required double gain}) =>
+    RustLib.instance.api.crateApiMixerSetLayerGain(layer: layer, gain: gain);
+
+Future<double> layerGain({required Layer layer}) =>
+    RustLib.instance.api.crateApiMixerLayerGain(layer: layer);
+
+Future<void> setLayerMuted({required Layer layer, required bool muted}) =>
+    RustLib.instance.api.crateApiMixerSetLayerMuted(layer: layer, muted: muted);
+
+Future<bool> isLayerMuted({required Layer layer}) =>
+    RustLib.instance.api.crateApiMixerIsLayerMuted(layer: layer);
+
+/// Stereo position of a layer, from -1.0 (left) to 1.0 (right).
+Future<void> setLayerPan({required Layer layer, required double pan}) =>
+    RustLib.instance.api.crateApiMixerSetLayerPan(layer: layer, pan: pan);
+
+Future<double> layerPan({required Layer layer}) =>
+    RustLib.instance.api.crateApiMixerLayerPan(layer: layer);
+
+/// Drone in the left ear, challenge and answer in the right ear.
+Future<void> setDichotic({required bool enabled}) =>
+    RustLib.instance.api.crateApiMixerSetDichotic(enabled: enabled);
+
+Future<bool> isDichotic() => RustLib.instance.api.crateApiMixerIsDichotic();
+
+Future<void> setMasterGain({required double gain}) =>
+    RustLib.instance.api.crateApiMixerSetMasterGain(gain: gain);
+
+Future<double> masterGain() => RustLib.instance.api.crateApiMixerMasterGain();
+
+Future<void> setCompressorEnabled({required bool enabled}) =>
+    RustLib.instance.api.crateApiMixerSetCompressorEnabled(enabled: enabled);
+
+Future<bool> isCompressorEnabled() =>
+    RustLib.instance.api.crateApiMixerIsCompressorEnabled();
+
+Future<void> setReverbRoomSize({required double roomSize}) =>
+    RustLib.instance.api.crateApiMixerSetReverbRoomSize(roomSize: roomSize);
+
+Future<double> reverbRoomSize() =>
+    RustLib.instance.api.crateApiMixerReverbRoomSize();
+
+Future<void> setReverbDamping({required double damping}) =>
+    RustLib.instance.api.crateApiMixerSetReverbDamping(damping: damping);
+
+Future<double> reverbDamping() =>
+    RustLib.instance.api.crateApiMixerReverbDamping();
+
+/// Reverb wet/dry balance, 0.0 turns the reverb off.
+Future<void> setReverbWet({required double wet}) =>
+    RustLib.instance.api.crateApiMixerSetReverbWet(wet: wet);
+
+Future<double> reverbWet() => RustLib.instance.api.crateApiMixerReverbWet();
+
+enum Layer {
+  drone,
+  challenge,
+  answer,
+  voice,
+  ;
+}
@@ -6,7 +6,63 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`, `fmt`, `hash`
+// These functions are ignored because they are not marked as `pub`: `degree_steps`, `parse_degree`, `root_spelling`, `spell_pitch`, `spell`
+// These types are ignored because they are not used by any `pub` functions: `CUSTOM_PRESETS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `from_str`, `hash`, `hash`, `initialize`, `try_from`
+
+/// Name of the key whose tonic is `root`, where `Note::One` is C, spelled
+/// for `scale`: D♭ major but C♯ minor.
+Future<String> keyName({required Note root, required Set<Note> scale}) =>
+    RustLib.instance.api.crateApiNotesKeyName(root: root, scale: scale);
+
+/// Absolute pitch class of `degree` in the key of `root`, spelled so each
+/// degree of `scale` keeps its own letter: b3 over D is F, b3 over E♭ is G♭
+/// and the #4 of B Locrian is F rather than E♯.
+Future<String> spellDegree(
+        {required Note root, required Note degree, required Set<Note> scale}) =>
+    RustLib.instance.api
+        .crateApiNotesSpellDegree(root: root, degree: degree, scale: scale);
+
+/// Root of the exercise being played, `None` when nothing is playing.
+Future<Note?> currentRoot() => RustLib.instance.api.crateApiNotesCurrentRoot();
+
+/// Key name of the exercise being played in `scale`, e.g. "B♭".
+Future<String?> currentKeyName({required Set<Note> scale}) =>
+    RustLib.instance.api.crateApiNotesCurrentKeyName(scale: scale);
+
+/// Parses a comma separated list of degrees, such as "1,b3,5".
+Future<Set<Note>> parseNotes({required String s}) =>
+    RustLib.instance.api.crateApiNotesParseNotes(s: s);
+
+Future<List<Preset>> allPresets() =>
+    RustLib.instance.api.crateApiNotesAllPresets();
+
+/// Formats degrees in scale order, the inverse of `parse_notes`.
+Future<String> formatNotes({required Set<Note> notes}) =>
+    RustLib.instance.api.crateApiNotesFormatNotes(notes: notes);
+
+/// Stores a named degree set, replacing any preset with the same name.
+Future<void> saveCustomPreset(
+        {required String name, required Set<Note> notes}) =>
+    RustLib.instance.api
+        .crateApiNotesSaveCustomPreset(name: name, notes: notes);
+
+Future<Set<Note>?> customPreset({required String name}) =>
+    RustLib.instance.api.crateApiNotesCustomPreset(name: name);
+
+Future<bool> deleteCustomPreset({required String name}) =>
+    RustLib.instance.api.crateApiNotesDeleteCustomPreset(name: name);
+
+Future<List<String>> customPresetNames() =>
+    RustLib.instance.api.crateApiNotesCustomPresetNames();
+
+/// Serializes the custom presets as "name: 1,b3,5" lines, for the app to persist.
+Future<String> exportCustomPresets() =>
+    RustLib.instance.api.crateApiNotesExportCustomPresets();
+
+/// Loads presets written by `export_custom_presets`, keeping existing ones.
+Future<void> importCustomPresets({required String data}) =>
+    RustLib.instance.api.crateApiNotesImportCustomPresets(data: data);
 
 Future<Set<Note>> getAllNotes() =>
     RustLib.instance.api.crateApiNotesGetAllNotes();
@@ -17,12 +73,13 @@ Future<void> stop() => RustLib.instance.api.crateApiNotesStop();
 
 Future<void> playExercise() => RustLib.instance.api.crateApiNotesPlayExercise();
 
+/// A scale degree. The discriminant is the number of semitones above the root.
 enum Note {
   one,
   flatTwo,
   two,
-  three,
   flatThree,
+  three,
   four,
   sharpFour,
   five,
@@ -35,6 +92,11 @@ enum Note {
   static Future<Note> fromNumber({required int n}) =>
       RustLib.instance.api.crateApiNotesNoteFromNumber(n: n);
 
+  /// Semitones above the root, the canonical numeric value of a degree.
+  Future<int> semitones() => RustLib.instance.api.crateApiNotesNoteSemitones(
+        that: this,
+      );
+
   Future<int> toKeyboardC1Note() =>
       RustLib.instance.api.crateApiNotesNoteToKeyboardC1Note(
         that: this,
@@ -50,3 +112,28 @@ enum Note {
         that: this,
       );
 }
+
+enum Preset {
+  major,
+  naturalMinor,
+  harmonicMinor,
+  melodicMinor,
+  dorian,
+  phrygian,
+  lydian,
+  mixolydian,
+  locrian,
+  majorPentatonic,
+  minorPentatonic,
+  blues,
+  chromatic,
+  ;
+
+  Future<String> name() => RustLib.instance.api.crateApiNotesPresetName(
+        that: this,
+      );
+
+  Future<Set<Note>> notes() => RustLib.instance.api.crateApiNotesPresetNotes(
+        that: this,
+      );
+}
@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'degrees.dart';
+import 'notes.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These types are ignored because they are not used by any `pub` functions: `AnswerOutcome`, `QUIZ`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `initialize`
+
+/// Checks the answer against the exercise that is playing right now.
+Future<AnswerFeedback> submitAnswer({required Note answer}) =>
+    RustLib.instance.api.crateApiQuizSubmitAnswer(answer: answer);
+
+/// Same as `submit_answer`, for sessions from `start_playing_degrees`.
+Future<AnswerFeedback> submitDegreeAnswer({required Degree answer}) =>
+    RustLib.instance.api.crateApiQuizSubmitDegreeAnswer(answer: answer);
+
+Future<Score> quizScore() => RustLib.instance.api.crateApiQuizQuizScore();
+
+Future<void> resetQuiz() => RustLib.instance.api.crateApiQuizResetQuiz();
+
+/// `AnswerOutcome` as plain data, the way Dart receives it.
+class AnswerFeedback {
+  final AnswerStatus status;
+  /// The degree that was playing, for an incorrect answer.
+  final Degree? expected;
+
+  const AnswerFeedback({
+    required this.status,
+    this.expected,
+  });
+
+  @override
+  int get hashCode => status.hashCode ^ expected.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnswerFeedback &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          expected == other.expected;
+}
+
+enum AnswerStatus {
+  correct,
+  incorrect,
+  alreadyAnswered,
+  revealed,
+  noExercise,
+  ;
+}
+
+class Score {
+  final int correct;
+  final int answered;
+
+  const Score({
+    required this.correct,
+    required this.answered,
+  });
+
+  static Future<Score> default_() =>
+      RustLib.instance.api.crateApiQuizScoreDefault();
+
+  @override
+  int get hashCode => correct.hashCode ^ answered.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Score &&
+          runtimeType == other.runtimeType &&
+          correct == other.correct &&
+          answered == other.answered;
+}
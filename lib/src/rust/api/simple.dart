@@ -4,12 +4,18 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'degrees.dart';
 import 'notes.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 Future<void> startPlaying({required Set<Note> notes}) =>
     RustLib.instance.api.crateApiSimpleStartPlaying(notes: notes);
 
+/// Plays a set of microtonal degrees, e.g. from `edo_degrees(24)`, instead
+/// of whole notes.
+Future<void> startPlayingDegrees({required List<Degree> degrees}) =>
+    RustLib.instance.api.crateApiSimpleStartPlayingDegrees(degrees: degrees);
+
 Future<void> stopPlaying() => RustLib.instance.api.crateApiSimpleStopPlaying();
 
 Future<void> initWavFilesFromBytes({required List<Uint8List> wavData}) =>
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'degrees.dart';
+import 'notes.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Starts listening on the default microphone, the drone keeps playing.
+Future<void> startListening() =>
+    RustLib.instance.api.crateApiSingingStartListening();
+
+Future<void> stopListening() =>
+    RustLib.instance.api.crateApiSingingStopListening();
+
+Future<bool> isListening() => RustLib.instance.api.crateApiSingingIsListening();
+
+/// What is being sung right now against the exercise that is playing,
+/// `None` while nothing is sung or no exercise is playing.
+Future<SungAnswer?> sungAnswer() =>
+    RustLib.instance.api.crateApiSingingSungAnswer();
+
+/// Same as `sung_answer` for a recording in any voice file format, `None`
+/// when nothing is sung in it.
+Future<SungAnswer?> sungAnswerFromRecording(
+        {required List<int> data,
+        required Note root,
+        required Degree expected}) =>
+    RustLib.instance.api.crateApiSingingSungAnswerFromRecording(
+        data: data, root: root, expected: expected);
+
+/// A sung pitch compared with the degree the exercise asked for.
+class SungAnswer {
+  final double frequency;
+  /// The nearest degree above the root, in any octave.
+  final Note sung;
+  final Degree expected;
+  /// How far the singing is from `expected`, negative when flat.
+  final double cents;
+  /// Within 50 cents of `expected`.
+  final bool correct;
+
+  const SungAnswer({
+    required this.frequency,
+    required this.sung,
+    required this.expected,
+    required this.cents,
+    required this.correct,
+  });
+
+  @override
+  int get hashCode =>
+      frequency.hashCode ^
+      sung.hashCode ^
+      expected.hashCode ^
+      cents.hashCode ^
+      correct.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SungAnswer &&
+          runtimeType == other.runtimeType &&
+          frequency == other.frequency &&
+          sung == other.sung &&
+          expected == other.expected &&
+          cents == other.cents &&
+          correct == other.correct;
+}
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `hash`
+
+/// Selects the tuning used from the next exercise on.
+///
+/// `reference_pitch` is the frequency of A4 in Hz, e.g. 440, 442, 432 or 415.
+Future<void> setTuning(
+        {required TuningSystem system, required double referencePitch}) =>
+    RustLib.instance.api.crateApiTuningSetTuning(
+        system: system, referencePitch: referencePitch);
+
+Future<TuningSystem> tuningSystem() =>
+    RustLib.instance.api.crateApiTuningTuningSystem();
+
+Future<double> referencePitch() =>
+    RustLib.instance.api.crateApiTuningReferencePitch();
+
+enum TuningSystem {
+  equalTemperament,
+  justIntonation,
+  pythagorean,
+  ;
+}
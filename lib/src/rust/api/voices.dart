@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.5.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Loads a voice pack from a directory with a `manifest.txt` of
+/// `degree: file` lines, or with files named `1.wav` to `12.wav`.
+Future<void> loadVoicePackFromDir(
+        {required String name, required String path}) =>
+    RustLib.instance.api
+        .crateApiVoicesLoadVoicePackFromDir(name: name, path: path);
+
+/// Loads a voice pack from a manifest and the bytes of each file it names.
+Future<void> loadVoicePackFromBundle(
+        {required String name,
+        required String manifest,
+        required Map<String, Uint8List> files}) =>
+    RustLib.instance.api.crateApiVoicesLoadVoicePackFromBundle(
+        name: name, manifest: manifest, files: files);
+
+/// Answers with the given pack from the next `start_playing` on.
+Future<void> setVoicePack({required String name}) =>
+    RustLib.instance.api.crateApiVoicesSetVoicePack(name: name);
+
+Future<String> voicePack() => RustLib.instance.api.crateApiVoicesVoicePack();
+
+Future<List<String>> voicePacks() =>
+    RustLib.instance.api.crateApiVoicesVoicePacks();
+
+/// Speaks `text` with the built-in synthesizer, mono samples at 48 kHz.
+Future<Float32List> speak({required String text}) =>
+    RustLib.instance.api.crateApiVoicesSpeak(text: text);
+
+/// Loads the default voices from a directory, see `load_voice_pack_from_dir`.
+Future<void> initVoicesFromDir({required String path}) =>
+    RustLib.instance.api.crateApiVoicesInitVoicesFromDir(path: path);
+
+/// Loads the default voices from the contents of their files, in degree
+/// order 1, b2, 2 and so on.
+Future<void> initVoicesFromBytes({required List<Uint8List> wavData}) =>
+    RustLib.instance.api.crateApiVoicesInitVoicesFromBytes(wavData: wavData);
+
+/// False until recorded voices are loaded, the synthesizer speaks until then.
+Future<bool> voicesLoaded() =>
+    RustLib.instance.api.crateApiVoicesVoicesLoaded();
+
+/// Speeds up or slows down the voice answers by `tempo`, 0.5 to 2, and shifts
+/// them by `pitch` semitones, -12 to 12, from the next `start_playing` on.
+Future<void> setVoiceStyle({required double tempo, required double pitch}) =>
+    RustLib.instance.api
+        .crateApiVoicesSetVoiceStyle(tempo: tempo, pitch: pitch);
+
+Future<double> voiceTempo() => RustLib.instance.api.crateApiVoicesVoiceTempo();
+
+Future<double> voicePitch() => RustLib.instance.api.crateApiVoicesVoicePitch();
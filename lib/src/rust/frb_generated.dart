@@ -3,8 +3,15 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/degrees.dart';
+import 'api/intonation.dart';
+import 'api/mixer.dart';
 import 'api/notes.dart';
+import 'api/quiz.dart';
 import 'api/simple.dart';
+import 'api/singing.dart';
+import 'api/tuning.dart';
+import 'api/voices.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -69,7 +76,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.5.0';
 
   @override
-  int get rustContentHash => 1602280700;
+  int get rustContentHash => -1053468554;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -80,22 +87,128 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  int crateApiDegreesDegreeCents({required Degree that});
+
+  Future<int> crateApiDegreesDegreeCentsAboveNote({required Degree that});
+
+  Future<Degree> crateApiDegreesDegreeFromCents({required double cents});
+
+  Degree crateApiDegreesDegreeNew({required int cents});
+
+  Future<Note?> crateApiDegreesDegreeNote({required Degree that});
+
+  Future<Note> crateApiDegreesDegreeNoteBelow({required Degree that});
+
+  Future<Set<Degree>> crateApiDegreesDegreesFromNotes(
+      {required Set<Note> notes});
+
+  Future<List<Degree>> crateApiDegreesEdoDegrees({required int divisions});
+
+  Future<Set<Degree>> crateApiDegreesParseDegrees({required String s});
+
+  Stream<IntonationReading> crateApiIntonationIntonationStream();
+
+  Future<bool> crateApiMixerIsCompressorEnabled();
+
+  Future<bool> crateApiMixerIsDichotic();
+
+  Future<bool> crateApiMixerIsLayerMuted({required Layer layer});
+
+  Future<double> crateApiMixerLayerGain({required Layer layer});
+
+  Future<double> crateApiMixerLayerPan({required Layer layer});
+
+  Future<double> crateApiMixerMasterGain();
+
+  Future<double> crateApiMixerReverbDamping();
+
+  Future<double> crateApiMixerReverbRoomSize();
+
+  Future<double> crateApiMixerReverbWet();
+
+  Future<void> crateApiMixerSetCompressorEnabled({required bool enabled});
+
+  Future<void> crateApiMixerSetDichotic({required bool enabled});
+
+  Future<void> crateApiMixerSetLayerGain(
+      {required Layer layer, required double gain});
+
+  Future<void> crateApiMixerSetLayerMuted(
+      {required Layer layer, required bool muted});
+
+  Future<void> crateApiMixerSetLayerPan(
+      {required Layer layer, required double pan});
+
+  Future<void> crateApiMixerSetMasterGain({required double gain});
+
+  Future<void> crateApiMixerSetReverbDamping({required double damping});
+
+  Future<void> crateApiMixerSetReverbRoomSize({required double roomSize});
+
+  Future<void> crateApiMixerSetReverbWet({required double wet});
+
   Future<List<Note>> crateApiNotesAllNotes();
 
+  Future<List<Preset>> crateApiNotesAllPresets();
+
+  Future<String?> crateApiNotesCurrentKeyName({required Set<Note> scale});
+
+  Future<Note?> crateApiNotesCurrentRoot();
+
+  Future<Set<Note>?> crateApiNotesCustomPreset({required String name});
+
+  Future<List<String>> crateApiNotesCustomPresetNames();
+
+  Future<bool> crateApiNotesDeleteCustomPreset({required String name});
+
+  Future<String> crateApiNotesExportCustomPresets();
+
+  Future<String> crateApiNotesFormatNotes({required Set<Note> notes});
+
   Future<Set<Note>> crateApiNotesGetAllNotes();
 
+  Future<void> crateApiNotesImportCustomPresets({required String data});
+
+  Future<String> crateApiNotesKeyName(
+      {required Note root, required Set<Note> scale});
+
   Future<Note> crateApiNotesNoteFromNumber({required int n});
 
+  Future<int> crateApiNotesNoteSemitones({required Note that});
+
   Future<int> crateApiNotesNoteToKeyboardC1Note({required Note that});
 
   Future<int> crateApiNotesNoteToKeyboardC5Note({required Note that});
 
   Future<int> crateApiNotesNoteToKeyboardNote({required Note that});
 
+  Future<Set<Note>> crateApiNotesParseNotes({required String s});
+
   Future<void> crateApiNotesPlayExercise();
 
+  Future<String> crateApiNotesPresetName({required Preset that});
+
+  Future<Set<Note>> crateApiNotesPresetNotes({required Preset that});
+
+  Future<void> crateApiNotesSaveCustomPreset(
+      {required String name, required Set<Note> notes});
+
+  Future<String> crateApiNotesSpellDegree(
+      {required Note root, required Note degree, required Set<Note> scale});
+
   Future<void> crateApiNotesStop();
 
+  Future<Score> crateApiQuizQuizScore();
+
+  Future<void> crateApiQuizResetQuiz();
+
+  Future<Score> crateApiQuizScoreDefault();
+
+  Future<AnswerFeedback> crateApiQuizSubmitAnswer({required Note answer});
+
+  Future<AnswerFeedback> crateApiQuizSubmitDegreeAnswer(
+      {required Degree answer});
+
   Future<void> crateApiSimpleInitApp();
 
   Future<void> crateApiSimpleInitWavFilesFromBytes(
@@ -103,7 +216,64 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiSimpleStartPlaying({required Set<Note> notes});
 
+  Future<void> crateApiSimpleStartPlayingDegrees(
+      {required List<Degree> degrees});
+
   Future<void> crateApiSimpleStopPlaying();
+
+  Future<bool> crateApiSingingIsListening();
+
+  Future<void> crateApiSingingStartListening();
+
+  Future<void> crateApiSingingStopListening();
+
+  Future<SungAnswer?> crateApiSingingSungAnswer();
+
+  Future<SungAnswer?> crateApiSingingSungAnswerFromRecording(
+      {required List<int> data, required Note root, required Degree expected});
+
+  Future<double> crateApiTuningReferencePitch();
+
+  Future<void> crateApiTuningSetTuning(
+      {required TuningSystem system, required double referencePitch});
+
+  Future<TuningSystem> crateApiTuningTuningSystem();
+
+  Future<void> crateApiVoicesInitVoicesFromBytes(
+      {required List<Uint8List> wavData});
+
+  Future<void> crateApiVoicesInitVoicesFromDir({required String path});
+
+  Future<void> crateApiVoicesLoadVoicePackFromBundle(
+      {required String name,
+      required String manifest,
+      required Map<String, Uint8List> files});
+
+  Future<void> crateApiVoicesLoadVoicePackFromDir(
+      {required String name, required String path});
+
+  Future<void> crateApiVoicesSetVoicePack({required String name});
+
+  Future<void> crateApiVoicesSetVoiceStyle(
+      {required double tempo, required double pitch});
+
+  Future<Float32List> crateApiVoicesSpeak({required String text});
+
+  Future<String> crateApiVoicesVoicePack();
+
+  Future<List<String>> crateApiVoicesVoicePacks();
+
+  Future<double> crateApiVoicesVoicePitch();
+
+  Future<double> crateApiVoicesVoiceTempo();
+
+  Future<bool> crateApiVoicesVoicesLoaded();
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Degree;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Degree;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DegreePtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -115,227 +285,236 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<List<Note>> crateApiNotesAllNotes() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  int crateApiDegreesDegreeCents({required Degree that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_note,
+        decodeSuccessData: sse_decode_u_16,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiNotesAllNotesConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDegreesDegreeCentsConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesAllNotesConstMeta => const TaskConstMeta(
-        debugName: "all_notes",
-        argNames: [],
+  TaskConstMeta get kCrateApiDegreesDegreeCentsConstMeta => const TaskConstMeta(
+        debugName: "Degree_cents",
+        argNames: ["that"],
       );
 
   @override
-  Future<Set<Note>> crateApiNotesGetAllNotes() {
+  Future<int> crateApiDegreesDegreeCentsAboveNote({required Degree that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_Set_note,
+        decodeSuccessData: sse_decode_u_16,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiNotesGetAllNotesConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDegreesDegreeCentsAboveNoteConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesGetAllNotesConstMeta => const TaskConstMeta(
-        debugName: "get_all_notes",
-        argNames: [],
+  TaskConstMeta get kCrateApiDegreesDegreeCentsAboveNoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "Degree_cents_above_note",
+        argNames: ["that"],
       );
 
   @override
-  Future<Note> crateApiNotesNoteFromNumber({required int n}) {
+  Future<Degree> crateApiDegreesDegreeFromCents({required double cents}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(n, serializer);
+        sse_encode_f_32(cents, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_note,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiNotesNoteFromNumberConstMeta,
-      argValues: [n],
+      constMeta: kCrateApiDegreesDegreeFromCentsConstMeta,
+      argValues: [cents],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesNoteFromNumberConstMeta =>
+  TaskConstMeta get kCrateApiDegreesDegreeFromCentsConstMeta =>
       const TaskConstMeta(
-        debugName: "note_from_number",
-        argNames: ["n"],
+        debugName: "Degree_from_cents",
+        argNames: ["cents"],
       );
 
   @override
-  Future<int> crateApiNotesNoteToKeyboardC1Note({required Note that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  Degree crateApiDegreesDegreeNew({required int cents}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_note(that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
+        sse_encode_u_16(cents, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiNotesNoteToKeyboardC1NoteConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiDegreesDegreeNewConstMeta,
+      argValues: [cents],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesNoteToKeyboardC1NoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "note_to_keyboard_c1_note",
-        argNames: ["that"],
+  TaskConstMeta get kCrateApiDegreesDegreeNewConstMeta => const TaskConstMeta(
+        debugName: "Degree_new",
+        argNames: ["cents"],
       );
 
   @override
-  Future<int> crateApiNotesNoteToKeyboardC5Note({required Note that}) {
+  Future<Note?> crateApiDegreesDegreeNote({required Degree that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_note(that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
+        decodeSuccessData: sse_decode_opt_box_autoadd_note,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiNotesNoteToKeyboardC5NoteConstMeta,
+      constMeta: kCrateApiDegreesDegreeNoteConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesNoteToKeyboardC5NoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "note_to_keyboard_c5_note",
+  TaskConstMeta get kCrateApiDegreesDegreeNoteConstMeta => const TaskConstMeta(
+        debugName: "Degree_note",
         argNames: ["that"],
       );
 
   @override
-  Future<int> crateApiNotesNoteToKeyboardNote({required Note that}) {
+  Future<Note> crateApiDegreesDegreeNoteBelow({required Degree that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_note(that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
+        decodeSuccessData: sse_decode_note,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiNotesNoteToKeyboardNoteConstMeta,
+      constMeta: kCrateApiDegreesDegreeNoteBelowConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesNoteToKeyboardNoteConstMeta =>
+  TaskConstMeta get kCrateApiDegreesDegreeNoteBelowConstMeta =>
       const TaskConstMeta(
-        debugName: "note_to_keyboard_note",
+        debugName: "Degree_note_below",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiNotesPlayExercise() {
+  Future<Set<Degree>> crateApiDegreesDegreesFromNotes(
+      {required Set<Note> notes}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Set_note(notes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiNotesPlayExerciseConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDegreesDegreesFromNotesConstMeta,
+      argValues: [notes],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesPlayExerciseConstMeta => const TaskConstMeta(
-        debugName: "play_exercise",
-        argNames: [],
+  TaskConstMeta get kCrateApiDegreesDegreesFromNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "degrees_from_notes",
+        argNames: ["notes"],
       );
 
   @override
-  Future<void> crateApiNotesStop() {
+  Future<List<Degree>> crateApiDegreesEdoDegrees({required int divisions}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_16(divisions, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiNotesStopConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDegreesEdoDegreesConstMeta,
+      argValues: [divisions],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiNotesStopConstMeta => const TaskConstMeta(
-        debugName: "stop",
-        argNames: [],
+  TaskConstMeta get kCrateApiDegreesEdoDegreesConstMeta => const TaskConstMeta(
+        debugName: "edo_degrees",
+        argNames: ["divisions"],
       );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<Set<Degree>> crateApiDegreesParseDegrees({required String s}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(s, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDegreesParseDegreesConstMeta,
+      argValues: [s],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
+  TaskConstMeta get kCrateApiDegreesParseDegreesConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_degrees",
+        argNames: ["s"],
       );
 
   @override
-  Future<void> crateApiSimpleInitWavFilesFromBytes(
-      {required List<Uint8List> wavData}) {
-    return handler.executeNormal(NormalTask(
+  Stream<IntonationReading> crateApiIntonationIntonationStream() {
+    final sink = RustStreamSink<IntonationReading>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_list_prim_u_8_strict(wavData, serializer);
+        sse_encode_StreamSink_intonation_reading_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
       },
@@ -343,44 +522,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleInitWavFilesFromBytesConstMeta,
-      argValues: [wavData],
+      constMeta: kCrateApiIntonationIntonationStreamConstMeta,
+      argValues: [sink],
       apiImpl: this,
-    ));
+    )));
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiSimpleInitWavFilesFromBytesConstMeta =>
+  TaskConstMeta get kCrateApiIntonationIntonationStreamConstMeta =>
       const TaskConstMeta(
-        debugName: "init_wav_files_from_bytes",
-        argNames: ["wavData"],
+        debugName: "intonation_stream",
+        argNames: ["sink"],
       );
 
   @override
-  Future<void> crateApiSimpleStartPlaying({required Set<Note> notes}) {
+  Future<bool> crateApiMixerIsCompressorEnabled() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Set_note(notes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleStartPlayingConstMeta,
-      argValues: [notes],
+      constMeta: kCrateApiMixerIsCompressorEnabledConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleStartPlayingConstMeta => const TaskConstMeta(
-        debugName: "start_playing",
-        argNames: ["notes"],
+  TaskConstMeta get kCrateApiMixerIsCompressorEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_compressor_enabled",
+        argNames: [],
       );
 
   @override
-  Future<void> crateApiSimpleStopPlaying() {
+  Future<bool> crateApiMixerIsDichotic() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -388,118 +568,2432 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleStopPlayingConstMeta,
+      constMeta: kCrateApiMixerIsDichoticConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleStopPlayingConstMeta => const TaskConstMeta(
-        debugName: "stop_playing",
+  TaskConstMeta get kCrateApiMixerIsDichoticConstMeta => const TaskConstMeta(
+        debugName: "is_dichotic",
         argNames: [],
       );
 
-  @protected
-  Set<Note> dco_decode_Set_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Set.from(dco_decode_list_note(raw));
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  Future<bool> crateApiMixerIsLayerMuted({required Layer layer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 13, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerIsLayerMutedConstMeta,
+      argValues: [layer],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
-  }
+  TaskConstMeta get kCrateApiMixerIsLayerMutedConstMeta => const TaskConstMeta(
+        debugName: "is_layer_muted",
+        argNames: ["layer"],
+      );
 
-  @protected
-  List<Note> dco_decode_list_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_note).toList();
+  @override
+  Future<double> crateApiMixerLayerGain({required Layer layer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 14, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerLayerGainConstMeta,
+      argValues: [layer],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
+  TaskConstMeta get kCrateApiMixerLayerGainConstMeta => const TaskConstMeta(
+        debugName: "layer_gain",
+        argNames: ["layer"],
+      );
 
-  @protected
-  Note dco_decode_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Note.values[raw as int];
+  @override
+  Future<double> crateApiMixerLayerPan({required Layer layer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerLayerPanConstMeta,
+      argValues: [layer],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
+  TaskConstMeta get kCrateApiMixerLayerPanConstMeta => const TaskConstMeta(
+        debugName: "layer_pan",
+        argNames: ["layer"],
+      );
 
-  @protected
+  @override
+  Future<double> crateApiMixerMasterGain() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerMasterGainConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerMasterGainConstMeta => const TaskConstMeta(
+        debugName: "master_gain",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiMixerReverbDamping() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 17, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerReverbDampingConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerReverbDampingConstMeta => const TaskConstMeta(
+        debugName: "reverb_damping",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiMixerReverbRoomSize() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 18, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerReverbRoomSizeConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerReverbRoomSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "reverb_room_size",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiMixerReverbWet() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 19, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerReverbWetConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerReverbWetConstMeta => const TaskConstMeta(
+        debugName: "reverb_wet",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiMixerSetCompressorEnabled({required bool enabled}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetCompressorEnabledConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetCompressorEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_compressor_enabled",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetDichotic({required bool enabled}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetDichoticConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetDichoticConstMeta => const TaskConstMeta(
+        debugName: "set_dichotic",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetLayerGain(
+      {required Layer layer, required double gain}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        sse_encode_f_32(gain, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetLayerGainConstMeta,
+      argValues: [layer, gain],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetLayerGainConstMeta => const TaskConstMeta(
+        debugName: "set_layer_gain",
+        argNames: ["layer", "gain"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetLayerMuted(
+      {required Layer layer, required bool muted}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        sse_encode_bool(muted, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetLayerMutedConstMeta,
+      argValues: [layer, muted],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetLayerMutedConstMeta => const TaskConstMeta(
+        debugName: "set_layer_muted",
+        argNames: ["layer", "muted"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetLayerPan(
+      {required Layer layer, required double pan}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_layer(layer, serializer);
+        sse_encode_f_32(pan, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetLayerPanConstMeta,
+      argValues: [layer, pan],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetLayerPanConstMeta => const TaskConstMeta(
+        debugName: "set_layer_pan",
+        argNames: ["layer", "pan"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetMasterGain({required double gain}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(gain, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetMasterGainConstMeta,
+      argValues: [gain],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetMasterGainConstMeta => const TaskConstMeta(
+        debugName: "set_master_gain",
+        argNames: ["gain"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetReverbDamping({required double damping}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(damping, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetReverbDampingConstMeta,
+      argValues: [damping],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetReverbDampingConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_reverb_damping",
+        argNames: ["damping"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetReverbRoomSize({required double roomSize}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(roomSize, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetReverbRoomSizeConstMeta,
+      argValues: [roomSize],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetReverbRoomSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_reverb_room_size",
+        argNames: ["roomSize"],
+      );
+
+  @override
+  Future<void> crateApiMixerSetReverbWet({required double wet}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(wet, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMixerSetReverbWetConstMeta,
+      argValues: [wet],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMixerSetReverbWetConstMeta => const TaskConstMeta(
+        debugName: "set_reverb_wet",
+        argNames: ["wet"],
+      );
+
+  @override
+  Future<List<Note>> crateApiNotesAllNotes() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesAllNotesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesAllNotesConstMeta => const TaskConstMeta(
+        debugName: "all_notes",
+        argNames: [],
+      );
+
+  @override
+  Future<List<Preset>> crateApiNotesAllPresets() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_preset,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesAllPresetsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesAllPresetsConstMeta => const TaskConstMeta(
+        debugName: "all_presets",
+        argNames: [],
+      );
+
+  @override
+  Future<String?> crateApiNotesCurrentKeyName({required Set<Note> scale}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Set_note(scale, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesCurrentKeyNameConstMeta,
+      argValues: [scale],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesCurrentKeyNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "current_key_name",
+        argNames: ["scale"],
+      );
+
+  @override
+  Future<Note?> crateApiNotesCurrentRoot() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesCurrentRootConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesCurrentRootConstMeta => const TaskConstMeta(
+        debugName: "current_root",
+        argNames: [],
+      );
+
+  @override
+  Future<Set<Note>?> crateApiNotesCustomPreset({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Set_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesCustomPresetConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesCustomPresetConstMeta => const TaskConstMeta(
+        debugName: "custom_preset",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<List<String>> crateApiNotesCustomPresetNames() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesCustomPresetNamesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesCustomPresetNamesConstMeta =>
+      const TaskConstMeta(
+        debugName: "custom_preset_names",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiNotesDeleteCustomPreset({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesDeleteCustomPresetConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesDeleteCustomPresetConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_custom_preset",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<String> crateApiNotesExportCustomPresets() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesExportCustomPresetsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesExportCustomPresetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_custom_presets",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiNotesFormatNotes({required Set<Note> notes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Set_note(notes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesFormatNotesConstMeta,
+      argValues: [notes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesFormatNotesConstMeta => const TaskConstMeta(
+        debugName: "format_notes",
+        argNames: ["notes"],
+      );
+
+  @override
+  Future<Set<Note>> crateApiNotesGetAllNotes() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Set_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesGetAllNotesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesGetAllNotesConstMeta => const TaskConstMeta(
+        debugName: "get_all_notes",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiNotesImportCustomPresets({required String data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiNotesImportCustomPresetsConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesImportCustomPresetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_custom_presets",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<String> crateApiNotesKeyName(
+      {required Note root, required Set<Note> scale}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(root, serializer);
+        sse_encode_Set_note(scale, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesKeyNameConstMeta,
+      argValues: [root, scale],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesKeyNameConstMeta => const TaskConstMeta(
+        debugName: "key_name",
+        argNames: ["root", "scale"],
+      );
+
+  @override
+  Future<Note> crateApiNotesNoteFromNumber({required int n}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(n, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesNoteFromNumberConstMeta,
+      argValues: [n],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesNoteFromNumberConstMeta =>
+      const TaskConstMeta(
+        debugName: "note_from_number",
+        argNames: ["n"],
+      );
+
+  @override
+  Future<int> crateApiNotesNoteSemitones({required Note that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_8,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesNoteSemitonesConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesNoteSemitonesConstMeta => const TaskConstMeta(
+        debugName: "note_semitones",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<int> crateApiNotesNoteToKeyboardC1Note({required Note that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesNoteToKeyboardC1NoteConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesNoteToKeyboardC1NoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "note_to_keyboard_c1_note",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<int> crateApiNotesNoteToKeyboardC5Note({required Note that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesNoteToKeyboardC5NoteConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesNoteToKeyboardC5NoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "note_to_keyboard_c5_note",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<int> crateApiNotesNoteToKeyboardNote({required Note that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesNoteToKeyboardNoteConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesNoteToKeyboardNoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "note_to_keyboard_note",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Set<Note>> crateApiNotesParseNotes({required String s}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(s, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Set_note,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiNotesParseNotesConstMeta,
+      argValues: [s],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesParseNotesConstMeta => const TaskConstMeta(
+        debugName: "parse_notes",
+        argNames: ["s"],
+      );
+
+  @override
+  Future<void> crateApiNotesPlayExercise() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesPlayExerciseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesPlayExerciseConstMeta => const TaskConstMeta(
+        debugName: "play_exercise",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiNotesPresetName({required Preset that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_preset(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesPresetNameConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesPresetNameConstMeta => const TaskConstMeta(
+        debugName: "preset_name",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Set<Note>> crateApiNotesPresetNotes({required Preset that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_preset(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Set_note,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesPresetNotesConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesPresetNotesConstMeta => const TaskConstMeta(
+        debugName: "preset_notes",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiNotesSaveCustomPreset(
+      {required String name, required Set<Note> notes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_Set_note(notes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiNotesSaveCustomPresetConstMeta,
+      argValues: [name, notes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesSaveCustomPresetConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_custom_preset",
+        argNames: ["name", "notes"],
+      );
+
+  @override
+  Future<String> crateApiNotesSpellDegree(
+      {required Note root, required Note degree, required Set<Note> scale}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(root, serializer);
+        sse_encode_note(degree, serializer);
+        sse_encode_Set_note(scale, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesSpellDegreeConstMeta,
+      argValues: [root, degree, scale],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesSpellDegreeConstMeta => const TaskConstMeta(
+        debugName: "spell_degree",
+        argNames: ["root", "degree", "scale"],
+      );
+
+  @override
+  Future<void> crateApiNotesStop() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotesStopConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiNotesStopConstMeta => const TaskConstMeta(
+        debugName: "stop",
+        argNames: [],
+      );
+
+  @override
+  Future<Score> crateApiQuizQuizScore() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_score,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiQuizQuizScoreConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiQuizQuizScoreConstMeta => const TaskConstMeta(
+        debugName: "quiz_score",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiQuizResetQuiz() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiQuizResetQuizConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiQuizResetQuizConstMeta => const TaskConstMeta(
+        debugName: "reset_quiz",
+        argNames: [],
+      );
+
+  @override
+  Future<Score> crateApiQuizScoreDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_score,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiQuizScoreDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiQuizScoreDefaultConstMeta => const TaskConstMeta(
+        debugName: "score_default",
+        argNames: [],
+      );
+
+  @override
+  Future<AnswerFeedback> crateApiQuizSubmitAnswer({required Note answer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_note(answer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_answer_feedback,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiQuizSubmitAnswerConstMeta,
+      argValues: [answer],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiQuizSubmitAnswerConstMeta => const TaskConstMeta(
+        debugName: "submit_answer",
+        argNames: ["answer"],
+      );
+
+  @override
+  Future<AnswerFeedback> crateApiQuizSubmitDegreeAnswer(
+      {required Degree answer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            answer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_answer_feedback,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiQuizSubmitDegreeAnswerConstMeta,
+      argValues: [answer],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiQuizSubmitDegreeAnswerConstMeta =>
+      const TaskConstMeta(
+        debugName: "submit_degree_answer",
+        argNames: ["answer"],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleInitAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitWavFilesFromBytes(
+      {required List<Uint8List> wavData}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_list_prim_u_8_strict(wavData, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleInitWavFilesFromBytesConstMeta,
+      argValues: [wavData],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitWavFilesFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_wav_files_from_bytes",
+        argNames: ["wavData"],
+      );
+
+  @override
+  Future<void> crateApiSimpleStartPlaying({required Set<Note> notes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Set_note(notes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleStartPlayingConstMeta,
+      argValues: [notes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartPlayingConstMeta => const TaskConstMeta(
+        debugName: "start_playing",
+        argNames: ["notes"],
+      );
+
+  @override
+  Future<void> crateApiSimpleStartPlayingDegrees(
+      {required List<Degree> degrees}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            degrees, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleStartPlayingDegreesConstMeta,
+      argValues: [degrees],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartPlayingDegreesConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_playing_degrees",
+        argNames: ["degrees"],
+      );
+
+  @override
+  Future<void> crateApiSimpleStopPlaying() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleStopPlayingConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleStopPlayingConstMeta => const TaskConstMeta(
+        debugName: "stop_playing",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiSingingIsListening() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSingingIsListeningConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSingingIsListeningConstMeta => const TaskConstMeta(
+        debugName: "is_listening",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiSingingStartListening() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSingingStartListeningConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSingingStartListeningConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_listening",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiSingingStopListening() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSingingStopListeningConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSingingStopListeningConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_listening",
+        argNames: [],
+      );
+
+  @override
+  Future<SungAnswer?> crateApiSingingSungAnswer() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_sung_answer,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSingingSungAnswerConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSingingSungAnswerConstMeta => const TaskConstMeta(
+        debugName: "sung_answer",
+        argNames: [],
+      );
+
+  @override
+  Future<SungAnswer?> crateApiSingingSungAnswerFromRecording(
+      {required List<int> data, required Note root, required Degree expected}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_note(root, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            expected, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_sung_answer,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSingingSungAnswerFromRecordingConstMeta,
+      argValues: [data, root, expected],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSingingSungAnswerFromRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "sung_answer_from_recording",
+        argNames: ["data", "root", "expected"],
+      );
+
+  @override
+  Future<double> crateApiTuningReferencePitch() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTuningReferencePitchConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTuningReferencePitchConstMeta =>
+      const TaskConstMeta(
+        debugName: "reference_pitch",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiTuningSetTuning(
+      {required TuningSystem system, required double referencePitch}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_tuning_system(system, serializer);
+        sse_encode_f_32(referencePitch, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 69, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiTuningSetTuningConstMeta,
+      argValues: [system, referencePitch],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTuningSetTuningConstMeta => const TaskConstMeta(
+        debugName: "set_tuning",
+        argNames: ["system", "referencePitch"],
+      );
+
+  @override
+  Future<TuningSystem> crateApiTuningTuningSystem() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tuning_system,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTuningTuningSystemConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTuningTuningSystemConstMeta => const TaskConstMeta(
+        debugName: "tuning_system",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiVoicesInitVoicesFromBytes(
+      {required List<Uint8List> wavData}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_list_prim_u_8_strict(wavData, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 71, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesInitVoicesFromBytesConstMeta,
+      argValues: [wavData],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesInitVoicesFromBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_voices_from_bytes",
+        argNames: ["wavData"],
+      );
+
+  @override
+  Future<void> crateApiVoicesInitVoicesFromDir({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesInitVoicesFromDirConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesInitVoicesFromDirConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_voices_from_dir",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiVoicesLoadVoicePackFromBundle(
+      {required String name,
+      required String manifest,
+      required Map<String, Uint8List> files}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(manifest, serializer);
+        sse_encode_Map_String_list_prim_u_8_strict(files, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesLoadVoicePackFromBundleConstMeta,
+      argValues: [name, manifest, files],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesLoadVoicePackFromBundleConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_voice_pack_from_bundle",
+        argNames: ["name", "manifest", "files"],
+      );
+
+  @override
+  Future<void> crateApiVoicesLoadVoicePackFromDir(
+      {required String name, required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesLoadVoicePackFromDirConstMeta,
+      argValues: [name, path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesLoadVoicePackFromDirConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_voice_pack_from_dir",
+        argNames: ["name", "path"],
+      );
+
+  @override
+  Future<void> crateApiVoicesSetVoicePack({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 75, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesSetVoicePackConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesSetVoicePackConstMeta => const TaskConstMeta(
+        debugName: "set_voice_pack",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<void> crateApiVoicesSetVoiceStyle(
+      {required double tempo, required double pitch}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(tempo, serializer);
+        sse_encode_f_32(pitch, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 76, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesSetVoiceStyleConstMeta,
+      argValues: [tempo, pitch],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesSetVoiceStyleConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_voice_style",
+        argNames: ["tempo", "pitch"],
+      );
+
+  @override
+  Future<Float32List> crateApiVoicesSpeak({required String text}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(text, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 77, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_f_32_strict,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesSpeakConstMeta,
+      argValues: [text],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesSpeakConstMeta => const TaskConstMeta(
+        debugName: "speak",
+        argNames: ["text"],
+      );
+
+  @override
+  Future<String> crateApiVoicesVoicePack() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesVoicePackConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesVoicePackConstMeta => const TaskConstMeta(
+        debugName: "voice_pack",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiVoicesVoicePacks() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 79, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiVoicesVoicePacksConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesVoicePacksConstMeta => const TaskConstMeta(
+        debugName: "voice_packs",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiVoicesVoicePitch() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 80, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiVoicesVoicePitchConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesVoicePitchConstMeta => const TaskConstMeta(
+        debugName: "voice_pitch",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiVoicesVoiceTempo() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 81, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiVoicesVoiceTempoConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesVoiceTempoConstMeta => const TaskConstMeta(
+        debugName: "voice_tempo",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiVoicesVoicesLoaded() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 82, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiVoicesVoicesLoadedConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVoicesVoicesLoadedConstMeta => const TaskConstMeta(
+        debugName: "voices_loaded",
+        argNames: [],
+      );
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Degree =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Degree =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Degree dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DegreeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Degree dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DegreeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, Uint8List> dco_decode_Map_String_list_prim_u_8_strict(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+        dco_decode_list_record_string_list_prim_u_8_strict(raw)
+            .map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Degree dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DegreeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Set<Degree> dco_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Set.from(
+        dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            raw));
+  }
+
+  @protected
+  Set<Note> dco_decode_Set_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Set.from(dco_decode_list_note(raw));
+  }
+
+  @protected
+  RustStreamSink<IntonationReading> dco_decode_StreamSink_intonation_reading_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AnswerFeedback dco_decode_answer_feedback(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AnswerFeedback(
+      status: dco_decode_answer_status(arr[0]),
+      expected: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+          arr[1]),
+    );
+  }
+
+  @protected
+  AnswerStatus dco_decode_answer_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnswerStatus.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Degree dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        raw);
+  }
+
+  @protected
+  Note dco_decode_box_autoadd_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_note(raw);
+  }
+
+  @protected
+  SungAnswer dco_decode_box_autoadd_sung_answer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sung_answer(raw);
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IntonationReading dco_decode_intonation_reading(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IntonationReading(
+      frequency: dco_decode_f_32(arr[0]),
+      note: dco_decode_note(arr[1]),
+      cents: dco_decode_f_32(arr[2]),
+      confidence: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  Layer dco_decode_layer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Layer.values[raw as int];
+  }
+
+  @protected
+  List<Degree> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree)
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  List<Note> dco_decode_list_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note).toList();
+  }
+
+  @protected
+  List<Preset> dco_decode_list_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_preset).toList();
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, Uint8List)> dco_decode_list_record_string_list_prim_u_8_strict(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_list_prim_u_8_strict)
+        .toList();
+  }
+
+  @protected
+  Note dco_decode_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Note.values[raw as int];
+  }
+
+  @protected
+  Set<Note>? dco_decode_opt_Set_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Set_note(raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  Degree? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+            raw);
+  }
+
+  @protected
+  Note? dco_decode_opt_box_autoadd_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_note(raw);
+  }
+
+  @protected
+  SungAnswer? dco_decode_opt_box_autoadd_sung_answer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sung_answer(raw);
+  }
+
+  @protected
+  Preset dco_decode_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Preset.values[raw as int];
+  }
+
+  @protected(String, Uint8List)
+  dco_decode_record_string_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  Score dco_decode_score(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Score(
+      correct: dco_decode_u_32(arr[0]),
+      answered: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SungAnswer dco_decode_sung_answer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SungAnswer(
+      frequency: dco_decode_f_32(arr[0]),
+      sung: dco_decode_note(arr[1]),
+      expected: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+          arr[2]),
+      cents: dco_decode_f_32(arr[3]),
+      correct: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  TuningSystem dco_decode_tuning_system(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TuningSystem.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
   void dco_decode_unit(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return;
   }
 
   @protected
-  Set<Note> sse_decode_Set_note(SseDeserializer deserializer) {
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Degree sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DegreeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Degree sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DegreeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Map<String, Uint8List> sse_decode_Map_String_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_list_record_string_list_prim_u_8_strict(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Degree sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DegreeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Set<Degree> sse_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        deserializer);
+    return Set.from(inner);
+  }
+
+  @protected
+  Set<Note> sse_decode_Set_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_note(deserializer);
+    return Set.from(inner);
+  }
+
+  @protected
+  RustStreamSink<IntonationReading> sse_decode_StreamSink_intonation_reading_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AnswerFeedback sse_decode_answer_feedback(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_answer_status(deserializer);
+    var var_expected =
+        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        deserializer);
+    return AnswerFeedback(status: var_status, expected: var_expected);
+  }
+
+  @protected
+  AnswerStatus sse_decode_answer_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AnswerStatus.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  Degree sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        deserializer));
+  }
+
+  @protected
+  Note sse_decode_box_autoadd_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_note(deserializer));
+  }
+
+  @protected
+  SungAnswer sse_decode_box_autoadd_sung_answer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sung_answer(deserializer));
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  IntonationReading sse_decode_intonation_reading(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frequency = sse_decode_f_32(deserializer);
+    var var_note = sse_decode_note(deserializer);
+    var var_cents = sse_decode_f_32(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    return IntonationReading(
+        frequency: var_frequency,
+        note: var_note,
+        cents: var_cents,
+        confidence: var_confidence);
+  }
+
+  @protected
+  Layer sse_decode_layer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Layer.values[inner];
+  }
+
+  @protected
+  List<Degree> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Degree>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+              deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint8List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Note> sse_decode_list_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Note>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Preset> sse_decode_list_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Preset>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_preset(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, Uint8List)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Note sse_decode_note(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_note(deserializer);
-    return Set.from(inner);
+    var inner = sse_decode_i_32(deserializer);
+    return Note.values[inner];
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  Set<Note>? sse_decode_opt_Set_note(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Set_note(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Degree? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+          deserializer));
+    } else {
+      return null;
     }
-    return ans_;
   }
 
   @protected
-  List<Note> sse_decode_list_note(SseDeserializer deserializer) {
+  Note? sse_decode_opt_box_autoadd_note(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Note>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_note(deserializer));
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_note(deserializer));
+    } else {
+      return null;
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  SungAnswer? sse_decode_opt_box_autoadd_sung_answer(
+      SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sung_answer(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Note sse_decode_note(SseDeserializer deserializer) {
+  Preset sse_decode_preset(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var inner = sse_decode_i_32(deserializer);
-    return Note.values[inner];
+    return Preset.values[inner];
+  }
+
+  @protected(String, Uint8List)
+  sse_decode_record_string_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  Score sse_decode_score(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_correct = sse_decode_u_32(deserializer);
+    var var_answered = sse_decode_u_32(deserializer);
+    return Score(correct: var_correct, answered: var_answered);
+  }
+
+  @protected
+  SungAnswer sse_decode_sung_answer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frequency = sse_decode_f_32(deserializer);
+    var var_sung = sse_decode_note(deserializer);
+    var var_expected =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        deserializer);
+    var var_cents = sse_decode_f_32(deserializer);
+    var var_correct = sse_decode_bool(deserializer);
+    return SungAnswer(
+        frequency: var_frequency,
+        sung: var_sung,
+        expected: var_expected,
+        cents: var_cents,
+        correct: var_correct);
+  }
+
+  @protected
+  TuningSystem sse_decode_tuning_system(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TuningSystem.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
@@ -514,9 +3008,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DegreeImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DegreeImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_list_prim_u_8_strict(
+      Map<String, Uint8List> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_list_prim_u_8_strict(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DegreeImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Set<Degree> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        self.toList(), serializer);
   }
 
   @protected
@@ -525,12 +3066,112 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_note(self.toList(), serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_intonation_reading_Sse(
+      RustStreamSink<IntonationReading> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+      decodeSuccessData: sse_decode_intonation_reading,
+      decodeErrorData: sse_decode_AnyhowException,
+    )), serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_answer_feedback(
+      AnswerFeedback self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_answer_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        self.expected, serializer);
+  }
+
+  @protected
+  void sse_encode_answer_status(AnswerStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_note(Note self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_note(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sung_answer(
+      SungAnswer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sung_answer(self, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putInt32(self);
   }
 
+  @protected
+  void sse_encode_intonation_reading(
+      IntonationReading self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.frequency, serializer);
+    sse_encode_note(self.note, serializer);
+    sse_encode_f_32(self.cents, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+  }
+
+  @protected
+  void sse_encode_layer(Layer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      List<Degree> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+          item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
       List<Uint8List> self, SseSerializer serializer) {
@@ -550,6 +3191,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_preset(List<Preset> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_preset(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+      Float32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+      List<int> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self is Uint8List?,
+        self: Uint8List.fromList(self));
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer) {
@@ -558,12 +3225,125 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_record_string_list_prim_u_8_strict(
+      List<(String, Uint8List)> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_list_prim_u_8_strict(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_note(Note self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_opt_Set_note(Set<Note>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Set_note(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+          self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_note(Note? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_note(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_sung_answer(
+      SungAnswer? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sung_answer(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_preset(Preset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_list_prim_u_8_strict(
+      (String, Uint8List) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_list_prim_u_8_strict(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_score(Score self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.correct, serializer);
+    sse_encode_u_32(self.answered, serializer);
+  }
+
+  @protected
+  void sse_encode_sung_answer(SungAnswer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.frequency, serializer);
+    sse_encode_note(self.sung, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+        self.expected, serializer);
+    sse_encode_f_32(self.cents, serializer);
+    sse_encode_bool(self.correct, serializer);
+  }
+
+  @protected
+  void sse_encode_tuning_system(TuningSystem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -576,8 +3356,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    serializer.buffer.putBigUint64(self);
   }
 }
+
+@sealed
+class DegreeImpl extends RustOpaque implements Degree {
+  // Not to be used by end users
+  DegreeImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  DegreeImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Degree,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Degree,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DegreePtr,
+  );
+
+  int get cents => RustLib.instance.api.crateApiDegreesDegreeCents(
+        that: this,
+      );
+
+  /// Cents above `note_below`.
+  Future<int> centsAboveNote() =>
+      RustLib.instance.api.crateApiDegreesDegreeCentsAboveNote(
+        that: this,
+      );
+
+  /// The `Note` this degree falls on, `None` for microtonal degrees.
+  Future<Note?> note() => RustLib.instance.api.crateApiDegreesDegreeNote(
+        that: this,
+      );
+
+  /// The nearest `Note` at or below this degree.
+  Future<Note> noteBelow() =>
+      RustLib.instance.api.crateApiDegreesDegreeNoteBelow(
+        that: this,
+      );
+}
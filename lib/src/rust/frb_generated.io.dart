@@ -3,8 +3,15 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/degrees.dart';
+import 'api/intonation.dart';
+import 'api/mixer.dart';
 import 'api/notes.dart';
+import 'api/quiz.dart';
 import 'api/simple.dart';
+import 'api/singing.dart';
+import 'api/tuning.dart';
+import 'api/voices.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -19,36 +26,222 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DegreePtr =>
+      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegreePtr;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  Degree dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  Degree dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  Map<String, Uint8List> dco_decode_Map_String_list_prim_u_8_strict(
+      dynamic raw);
+
+  @protected
+  Degree dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  Set<Degree> dco_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
   @protected
   Set<Note> dco_decode_Set_note(dynamic raw);
 
+  @protected
+  RustStreamSink<IntonationReading> dco_decode_StreamSink_intonation_reading_Sse(
+      dynamic raw);
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AnswerFeedback dco_decode_answer_feedback(dynamic raw);
+
+  @protected
+  AnswerStatus dco_decode_answer_status(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  Degree dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  Note dco_decode_box_autoadd_note(dynamic raw);
+
+  @protected
+  SungAnswer dco_decode_box_autoadd_sung_answer(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  IntonationReading dco_decode_intonation_reading(dynamic raw);
+
+  @protected
+  Layer dco_decode_layer(dynamic raw);
+
+  @protected
+  List<Degree> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
   @protected
   List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<Note> dco_decode_list_note(dynamic raw);
 
+  @protected
+  List<Preset> dco_decode_list_preset(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, Uint8List)> dco_decode_list_record_string_list_prim_u_8_strict(
+      dynamic raw);
+
   @protected
   Note dco_decode_note(dynamic raw);
 
+  @protected
+  Set<Note>? dco_decode_opt_Set_note(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  Degree? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      dynamic raw);
+
+  @protected
+  Note? dco_decode_opt_box_autoadd_note(dynamic raw);
+
+  @protected
+  SungAnswer? dco_decode_opt_box_autoadd_sung_answer(dynamic raw);
+
+  @protected
+  Preset dco_decode_preset(dynamic raw);
+
+  @protected(String, Uint8List)
+  dco_decode_record_string_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  Score dco_decode_score(dynamic raw);
+
+  @protected
+  SungAnswer dco_decode_sung_answer(dynamic raw);
+
+  @protected
+  TuningSystem dco_decode_tuning_system(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Degree sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  Degree sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  Map<String, Uint8List> sse_decode_Map_String_list_prim_u_8_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  Degree sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  Set<Degree> sse_decode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
   @protected
   Set<Note> sse_decode_Set_note(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<IntonationReading> sse_decode_StreamSink_intonation_reading_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AnswerFeedback sse_decode_answer_feedback(SseDeserializer deserializer);
+
+  @protected
+  AnswerStatus sse_decode_answer_status(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  Degree sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  Note sse_decode_box_autoadd_note(SseDeserializer deserializer);
+
+  @protected
+  SungAnswer sse_decode_box_autoadd_sung_answer(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  IntonationReading sse_decode_intonation_reading(SseDeserializer deserializer);
+
+  @protected
+  Layer sse_decode_layer(SseDeserializer deserializer);
+
+  @protected
+  List<Degree> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
       SseDeserializer deserializer);
@@ -56,12 +249,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Note> sse_decode_list_note(SseDeserializer deserializer);
 
+  @protected
+  List<Preset> sse_decode_list_preset(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<(String, Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(
+      SseDeserializer deserializer);
+
   @protected
   Note sse_decode_note(SseDeserializer deserializer);
 
+  @protected
+  Set<Note>? sse_decode_opt_Set_note(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  Degree? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      SseDeserializer deserializer);
+
+  @protected
+  Note? sse_decode_opt_box_autoadd_note(SseDeserializer deserializer);
+
+  @protected
+  SungAnswer? sse_decode_opt_box_autoadd_sung_answer(
+      SseDeserializer deserializer);
+
+  @protected
+  Preset sse_decode_preset(SseDeserializer deserializer);
+
+  @protected(String, Uint8List)
+  sse_decode_record_string_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  Score sse_decode_score(SseDeserializer deserializer);
+
+  @protected
+  SungAnswer sse_decode_sung_answer(SseDeserializer deserializer);
+
+  @protected
+  TuningSystem sse_decode_tuning_system(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -69,14 +313,83 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Map_String_list_prim_u_8_strict(
+      Map<String, Uint8List> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Set_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Set<Degree> self, SseSerializer serializer);
 
   @protected
   void sse_encode_Set_note(Set<Note> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_intonation_reading_Sse(
+      RustStreamSink<IntonationReading> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_answer_feedback(
+      AnswerFeedback self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_answer_status(AnswerStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_note(Note self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sung_answer(
+      SungAnswer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_intonation_reading(
+      IntonationReading self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_layer(Layer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      List<Degree> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
       List<Uint8List> self, SseSerializer serializer);
@@ -84,13 +397,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_note(List<Note> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_preset(List<Preset> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+      Float32List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_record_string_list_prim_u_8_strict(
+      List<(String, Uint8List)> self, SseSerializer serializer);
+
   @protected
   void sse_encode_note(Note self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_Set_note(Set<Note>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      Degree? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_note(Note? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_sung_answer(
+      SungAnswer? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_preset(Preset self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_list_prim_u_8_strict(
+      (String, Uint8List) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_score(Score self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sung_answer(SungAnswer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tuning_system(TuningSystem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -98,7 +464,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -114,4 +480,34 @@ class RustLibWire implements BaseWire {
   /// The symbols are looked up in [dynamicLibrary].
   RustLibWire(ffi.DynamicLibrary dynamicLibrary)
       : _lookup = dynamicLibrary.lookup;
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegreePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+      'frbgen_tonestep_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree');
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegreePtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegreePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+      'frbgen_tonestep_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree');
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegree =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDegreePtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
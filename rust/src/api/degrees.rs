@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use crate::api::notes::Note;
+
+pub const CENTS_PER_OCTAVE: u16 = 1200;
+const CENTS_PER_SEMITONE: u16 = 100;
+const QUARTER_TONE: u16 = 50;
+
+/// A degree above the root in whole cents, `0..1200`.
+///
+/// The twelve `Note` degrees are the multiples of 100, anything in between is
+/// a microtonal degree. Whole cents are finer than the ear can tell apart in
+/// a drone exercise, which keeps degrees hashable for degree sets.
+///
+/// Built through `new` or `from_cents`, so a degree is always inside the octave.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Hash, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Degree {
+    cents: u16,
+}
+
+impl Degree {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(cents: u16) -> Result<Degree, String> {
+        if cents >= CENTS_PER_OCTAVE {
+            return Err(format!(
+                "Degree must be between 0 and {} cents, got {}",
+                CENTS_PER_OCTAVE, cents
+            ));
+        }
+        Ok(Degree { cents })
+    }
+
+    /// Rounds `cents` to whole cents.
+    pub fn from_cents(cents: f32) -> Result<Degree, String> {
+        let rounded = cents.round();
+        if !(0.0..CENTS_PER_OCTAVE as f32).contains(&rounded) {
+            return Err(format!(
+                "Degree must be between 0 and {} cents, got {}",
+                CENTS_PER_OCTAVE, cents
+            ));
+        }
+        Degree::new(rounded as u16)
+    }
+
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn cents(&self) -> u16 {
+        self.cents
+    }
+
+    /// The `Note` this degree falls on, `None` for microtonal degrees.
+    pub fn note(&self) -> Option<Note> {
+        if !self.cents.is_multiple_of(CENTS_PER_SEMITONE) {
+            return None;
+        }
+        Note::try_from((self.cents / CENTS_PER_SEMITONE) as u8).ok()
+    }
+
+    /// The nearest `Note` at or below this degree.
+    pub fn note_below(&self) -> Note {
+        Note::try_from((self.cents / CENTS_PER_SEMITONE) as u8).unwrap()
+    }
+
+    /// Cents above `note_below`.
+    pub fn cents_above_note(&self) -> u16 {
+        self.cents % CENTS_PER_SEMITONE
+    }
+}
+
+impl From<Note> for Degree {
+    fn from(note: Note) -> Self {
+        Degree {
+            cents: note.semitones() as u16 * CENTS_PER_SEMITONE,
+        }
+    }
+}
+
+impl std::fmt::Display for Degree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.cents_above_note() {
+            0 => write!(f, "{}", self.note_below()),
+            QUARTER_TONE => write!(f, "{}+", self.note_below()),
+            _ => write!(f, "{}c", self.cents),
+        }
+    }
+}
+
+impl std::str::FromStr for Degree {
+    type Err = String;
+
+    /// Accepts any `Note` name, a `Note` name raised or lowered a quarter
+    /// tone with `+` or `-` (b3+ and 3- are both 350 cents), or cents with a
+    /// `c` suffix like `350c`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(cents) = s.strip_suffix('c') {
+            let cents: f32 = cents
+                .trim()
+                .parse()
+                .map_err(|_| format!("Unknown degree: {}", s))?;
+            return Degree::from_cents(cents);
+        }
+
+        let (name, offset) = if let Some(name) = s.strip_suffix('+') {
+            (name, QUARTER_TONE as i32)
+        } else if let Some(name) = s.strip_suffix('-') {
+            (name, -(QUARTER_TONE as i32))
+        } else {
+            (s, 0)
+        };
+        let note: Note = name.parse()?;
+        let cents = (Degree::from(note).cents as i32 + offset).rem_euclid(CENTS_PER_OCTAVE as i32);
+        Ok(Degree {
+            cents: cents as u16,
+        })
+    }
+}
+
+/// Comma separated degrees, e.g. `1,b3+,5,1050c`.
+pub fn parse_degrees(s: &str) -> Result<HashSet<Degree>, String> {
+    s.split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| part.parse())
+        .collect()
+}
+
+pub fn degrees_from_notes(notes: &HashSet<Note>) -> HashSet<Degree> {
+    notes.iter().map(|&note| Degree::from(note)).collect()
+}
+
+/// All the degrees of an equal division of the octave, 24 gives quarter tones.
+pub fn edo_degrees(divisions: u16) -> Result<Vec<Degree>, String> {
+    if divisions == 0 || divisions > CENTS_PER_OCTAVE {
+        return Err(format!(
+            "Divisions must be between 1 and {}, got {}",
+            CENTS_PER_OCTAVE, divisions
+        ));
+    }
+    (0..divisions)
+        .map(|step| Degree::from_cents(step as f32 * CENTS_PER_OCTAVE as f32 / divisions as f32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::notes::ALL_NOTES;
+    use test_log::test;
+
+    #[test]
+    fn test_notes_are_whole_semitones() {
+        for note in ALL_NOTES {
+            let degree = Degree::from(note);
+            assert_eq!(degree.note(), Some(note));
+            assert_eq!(degree.to_string(), note.to_string());
+        }
+        assert_eq!(Degree { cents: 350 }.note(), None);
+        assert_eq!(Degree { cents: 350 }.note_below(), Note::FlatThree);
+    }
+
+    #[test]
+    fn test_degrees_stay_inside_the_octave() {
+        assert_eq!(Degree::new(1199).map(|degree| degree.cents()), Ok(1199));
+        assert!(Degree::new(1200).is_err());
+        assert!(Degree::new(u16::MAX).is_err());
+        assert!(Degree::from_cents(-0.6).is_err());
+        assert!(Degree::from_cents(f32::NAN).is_err());
+    }
+
+    #[test]
+    fn test_parse_degrees() {
+        assert_eq!("b3+".parse(), Ok(Degree { cents: 350 }));
+        assert_eq!("3-".parse(), Ok(Degree { cents: 350 }));
+        assert_eq!("1-".parse(), Ok(Degree { cents: 1150 }));
+        assert_eq!("do".parse(), Ok(Degree { cents: 0 }));
+        assert_eq!("386.3c".parse(), Ok(Degree { cents: 386 }));
+        assert!("1200c".parse::<Degree>().is_err());
+        assert!("x+".parse::<Degree>().is_err());
+
+        assert_eq!(
+            parse_degrees("1, b3+, 5"),
+            Ok(HashSet::from([
+                Degree { cents: 0 },
+                Degree { cents: 350 },
+                Degree { cents: 700 }
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for degree in edo_degrees(24).unwrap() {
+            assert_eq!(degree.to_string().parse(), Ok(degree));
+        }
+        assert_eq!(Degree { cents: 350 }.to_string(), "b3+");
+        assert_eq!(Degree { cents: 1017 }.to_string(), "1017c");
+        assert_eq!("1017c".parse(), Ok(Degree { cents: 1017 }));
+    }
+
+    #[test]
+    fn test_edo_degrees() {
+        let quarter_tones = edo_degrees(24).unwrap();
+        assert_eq!(quarter_tones.len(), 24);
+        assert_eq!(quarter_tones[7], Degree { cents: 350 });
+
+        let twelve: Vec<Degree> = ALL_NOTES.into_iter().map(Degree::from).collect();
+        assert_eq!(edo_degrees(12).unwrap(), twelve);
+
+        assert_eq!(edo_degrees(31).unwrap()[10], Degree { cents: 387 });
+        assert!(edo_degrees(0).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::api::notes::Note;
+use crate::frb_generated::StreamSink;
+use crate::player::intonation;
+
+// A meter redraws at most this often, batches of readings go out in between
+const STREAM_INTERVAL: Duration = Duration::from_millis(30);
+
+/// Where a sung or played pitch sits against the drone.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IntonationReading {
+    pub frequency: f32,
+    /// The nearest degree above the current root, in any octave.
+    pub note: Note,
+    /// Offset from `note`, negative when flat.
+    pub cents: f32,
+    /// How clearly pitched the input is, from 0 to 1.
+    pub confidence: f32,
+}
+
+/// Streams readings to Dart, oldest first, about 90 a second while
+/// something is sung.
+///
+/// Readings are collected while `start_listening` is active and an exercise
+/// is playing, so the drone keeps sounding as the reference. The stream is
+/// fed until `stop_listening` or `stop_playing`, or until another stream is
+/// opened.
+pub fn intonation_stream(sink: StreamSink<IntonationReading>) {
+    let generation = intonation::open_stream();
+    thread::spawn(move || {
+        while intonation::is_stream_open(generation) {
+            for reading in intonation::drain_readings() {
+                // Dart has closed the stream
+                if sink.add(reading).is_err() {
+                    return;
+                }
+            }
+            thread::sleep(STREAM_INTERVAL);
+        }
+    });
+}
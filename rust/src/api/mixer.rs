@@ -0,0 +1,84 @@
+use crate::player::{mixer, reverb};
+
+#[derive(Hash, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layer {
+    Drone,
+    Challenge,
+    Answer,
+    Voice,
+}
+
+pub fn set_layer_gain(layer: Layer, gain: f32) {
+    mixer::settings().layer(layer).set_gain(gain);
+}
+
+pub fn layer_gain(layer: Layer) -> f32 {
+    mixer::settings().layer(layer).gain()
+}
+
+pub fn set_layer_muted(layer: Layer, muted: bool) {
+    mixer::settings().layer(layer).set_muted(muted);
+}
+
+pub fn is_layer_muted(layer: Layer) -> bool {
+    mixer::settings().layer(layer).is_muted()
+}
+
+/// Stereo position of a layer, from -1.0 (left) to 1.0 (right).
+pub fn set_layer_pan(layer: Layer, pan: f32) {
+    mixer::settings().layer(layer).set_pan(pan);
+}
+
+pub fn layer_pan(layer: Layer) -> f32 {
+    mixer::settings().layer(layer).pan()
+}
+
+/// Drone in the left ear, challenge and answer in the right ear.
+pub fn set_dichotic(enabled: bool) {
+    mixer::settings().set_dichotic(enabled);
+}
+
+pub fn is_dichotic() -> bool {
+    mixer::settings().is_dichotic()
+}
+
+pub fn set_master_gain(gain: f32) {
+    mixer::settings().set_master_gain(gain);
+}
+
+pub fn master_gain() -> f32 {
+    mixer::settings().master_gain()
+}
+
+pub fn set_compressor_enabled(enabled: bool) {
+    mixer::settings().set_compressor_enabled(enabled);
+}
+
+pub fn is_compressor_enabled() -> bool {
+    mixer::settings().is_compressor_enabled()
+}
+
+pub fn set_reverb_room_size(room_size: f32) {
+    reverb::settings().set_room_size(room_size);
+}
+
+pub fn reverb_room_size() -> f32 {
+    reverb::settings().room_size()
+}
+
+pub fn set_reverb_damping(damping: f32) {
+    reverb::settings().set_damping(damping);
+}
+
+pub fn reverb_damping() -> f32 {
+    reverb::settings().damping()
+}
+
+/// Reverb wet/dry balance, 0.0 turns the reverb off.
+pub fn set_reverb_wet(wet: f32) {
+    reverb::settings().set_wet(wet);
+}
+
+pub fn reverb_wet() -> f32 {
+    reverb::settings().wet()
+}
@@ -1,2 +1,9 @@
+pub mod degrees;
+pub mod intonation;
+pub mod mixer;
 pub mod notes;
+pub mod quiz;
 pub mod simple;
+pub mod singing;
+pub mod tuning;
+pub mod voices;
@@ -1,56 +1,43 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
 
+use lazy_static::lazy_static;
+
+use crate::player::session;
+
+/// A scale degree. The discriminant is the number of semitones above the root.
 #[repr(C)]
 #[derive(Hash, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Note {
-    One,
-    FlatTwo,
-    Two,
-    Three,
-    FlatThree,
-    Four,
-    SharpFour,
-    Five,
-    FlatSix,
-    Six,
-    FlatSeven,
-    Seven,
+    One = 0,
+    FlatTwo = 1,
+    Two = 2,
+    FlatThree = 3,
+    Three = 4,
+    Four = 5,
+    SharpFour = 6,
+    Five = 7,
+    FlatSix = 8,
+    Six = 9,
+    FlatSeven = 10,
+    Seven = 11,
 }
 
 impl Note {
     pub fn from_number(n: i32) -> Note {
-        match n {
-            0 => Note::One,
-            1 => Note::FlatTwo,
-            2 => Note::Two,
-            3 => Note::FlatThree,
-            4 => Note::Three,
-            5 => Note::Four,
-            6 => Note::SharpFour,
-            7 => Note::Five,
-            8 => Note::FlatSix,
-            9 => Note::Six,
-            10 => Note::FlatSeven,
-            11 => Note::Seven,
-            _ => unreachable!(),
-        }
+        u8::try_from(n)
+            .ok()
+            .and_then(|n| Note::try_from(n).ok())
+            .unwrap_or_else(|| unreachable!())
+    }
+
+    /// Semitones above the root, the canonical numeric value of a degree.
+    pub fn semitones(&self) -> u8 {
+        *self as u8
     }
 
     pub fn to_keyboard_note(&self) -> i32 {
-        match self {
-            Note::One => 1,
-            Note::FlatTwo => 2,
-            Note::Two => 3,
-            Note::FlatThree => 4,
-            Note::Three => 5,
-            Note::Four => 6,
-            Note::SharpFour => 7,
-            Note::Five => 8,
-            Note::FlatSix => 9,
-            Note::Six => 10,
-            Note::FlatSeven => 11,
-            Note::Seven => 12,
-        }
+        self.semitones() as i32 + 1
     }
 
     pub fn to_keyboard_c1_note(&self) -> i32 {
@@ -62,6 +49,17 @@ impl Note {
     }
 }
 
+impl TryFrom<u8> for Note {
+    type Error = String;
+
+    fn try_from(semitones: u8) -> Result<Self, Self::Error> {
+        ALL_NOTES
+            .get(semitones as usize)
+            .copied()
+            .ok_or_else(|| format!("No degree is {} semitones above the root", semitones))
+    }
+}
+
 // Convert the enum to a displayable string
 impl std::fmt::Display for Note {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -83,39 +81,702 @@ impl std::fmt::Display for Note {
     }
 }
 
+// Semitones above the root of the natural degrees 1 to 7
+const MAJOR_SCALE: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+const SOLFEGE: [(&str, i32); 18] = [
+    ("do", 0),
+    ("di", 1),
+    ("ra", 1),
+    ("re", 2),
+    ("ri", 3),
+    ("me", 3),
+    ("mi", 4),
+    ("fa", 5),
+    ("fi", 6),
+    ("se", 6),
+    ("sol", 7),
+    ("so", 7),
+    ("si", 8),
+    ("le", 8),
+    ("la", 9),
+    ("li", 10),
+    ("te", 10),
+    ("ti", 11),
+];
+
+const INTERVALS: [(&str, i32); 17] = [
+    ("P1", 0),
+    ("U", 0),
+    ("m2", 1),
+    ("M2", 2),
+    ("m3", 3),
+    ("M3", 4),
+    ("P4", 5),
+    ("A4", 6),
+    ("TT", 6),
+    ("d5", 6),
+    ("P5", 7),
+    ("A5", 8),
+    ("m6", 8),
+    ("M6", 9),
+    ("m7", 10),
+    ("M7", 11),
+    ("P8", 0),
+];
+
+// Accidentals followed by a degree number, e.g. "b3", "#4" or "bb7"
+fn parse_degree(s: &str) -> Option<i32> {
+    let digits = s.trim_start_matches(['b', '#', '\u{266d}', '\u{266f}']);
+    let accidentals = &s[..s.len() - digits.len()];
+    let degree: usize = digits.parse().ok()?;
+    if !(1..=7).contains(&degree) {
+        return None;
+    }
+    let offset: i32 = accidentals
+        .chars()
+        .map(|c| if c == 'b' || c == '\u{266d}' { -1 } else { 1 })
+        .sum();
+    Some(MAJOR_SCALE[degree - 1] + offset)
+}
+
+/// Parses a scale degree.
+///
+/// Accepts the display form ("b3", "#4"), any other spelling with flats and
+/// sharps ("#2", "b5"), movable-do solfège ("me", "fi") and interval names
+/// ("m3", "TT", "P5").
+impl std::str::FromStr for Note {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let lowercase = trimmed.to_lowercase();
+
+        let semitones = parse_degree(trimmed)
+            .or_else(|| {
+                SOLFEGE
+                    .iter()
+                    .find(|(name, _)| *name == lowercase)
+                    .map(|&(_, semitones)| semitones)
+            })
+            .or_else(|| {
+                INTERVALS
+                    .iter()
+                    .find(|(name, _)| *name == trimmed)
+                    .map(|&(_, semitones)| semitones)
+            })
+            .ok_or_else(|| format!("Unknown note: {}", s))?;
+
+        Note::try_from(semitones.rem_euclid(12) as u8)
+    }
+}
+
+const LETTERS: [char; 7] = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+
+// Conventional key name of each root, as (letter index, accidental)
+const KEY_SPELLINGS: [(usize, i32); 12] = [
+    (0, 0),  // C
+    (1, -1), // D♭
+    (1, 0),  // D
+    (2, -1), // E♭
+    (2, 0),  // E
+    (3, 0),  // F
+    (3, 1),  // F♯
+    (4, 0),  // G
+    (5, -1), // A♭
+    (5, 0),  // A
+    (6, -1), // B♭
+    (6, 0),  // B
+];
+
+// Letter steps above the root for each degree, e.g. b3 and 3 are both a third
+const DEGREE_STEPS: [usize; 12] = [0, 1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6];
+
+// Letter steps of a seven note scale use every letter once, so the #4 of
+// Locrian is a fifth. Other scales, and degrees outside the scale, keep
+// `DEGREE_STEPS`.
+fn degree_steps(scale: &HashSet<Note>) -> [usize; 12] {
+    let mut steps = DEGREE_STEPS;
+    if scale.len() != 7 || !scale.contains(&Note::One) {
+        return steps;
+    }
+
+    let mut degrees: Vec<u8> = scale.iter().map(Note::semitones).collect();
+    degrees.sort();
+    // A scale like 1,b2,2,b3,3,4,5 would need double accidentals
+    if degrees
+        .iter()
+        .enumerate()
+        .any(|(step, &semitones)| (semitones as i32 - MAJOR_SCALE[step]).abs() > 1)
+    {
+        return steps;
+    }
+    for (step, semitones) in degrees.into_iter().enumerate() {
+        steps[semitones as usize] = step;
+    }
+    steps
+}
+
+fn spell(letter: usize, accidental: i32) -> String {
+    let accidental = match accidental {
+        -2 => "\u{1d12b}",
+        -1 => "\u{266d}",
+        1 => "\u{266f}",
+        2 => "\u{1d12a}",
+        _ => "",
+    };
+    format!("{}{}", LETTERS[letter], accidental)
+}
+
+fn spell_pitch(
+    (root_letter, root_accidental): (usize, i32),
+    steps: &[usize; 12],
+    degree: Note,
+) -> (usize, i32) {
+    let letter = (root_letter + steps[degree.semitones() as usize]) % 7;
+    let root_pitch = MAJOR_SCALE[root_letter] + root_accidental;
+    let pitch = root_pitch + degree.semitones() as i32;
+    let accidental = (pitch - MAJOR_SCALE[letter] + 6).rem_euclid(12) - 6;
+    (letter, accidental)
+}
+
+// Conventional spelling of `root`, unless its enharmonic needs fewer
+// accidentals across `scale`: C♯ minor rather than D♭ minor.
+fn root_spelling(root: Note, steps: &[usize; 12], scale: &HashSet<Note>) -> (usize, i32) {
+    let conventional = KEY_SPELLINGS[root.semitones() as usize];
+    let (letter, accidental) = conventional;
+    // D♭ is C♯ and F♯ is G♭
+    let enharmonic = (
+        (letter as i32 + accidental).rem_euclid(7) as usize,
+        -accidental,
+    );
+    let accidentals = |spelling: (usize, i32)| -> i32 {
+        scale
+            .iter()
+            .map(|&degree| spell_pitch(spelling, steps, degree).1.abs())
+            .sum::<i32>()
+            + spelling.1.abs()
+    };
+    if accidental != 0 && accidentals(enharmonic) < accidentals(conventional) {
+        enharmonic
+    } else {
+        conventional
+    }
+}
+
+/// Name of the key whose tonic is `root`, where `Note::One` is C, spelled
+/// for `scale`: D♭ major but C♯ minor.
+pub fn key_name(root: Note, scale: &HashSet<Note>) -> String {
+    let steps = degree_steps(scale);
+    let (letter, accidental) = root_spelling(root, &steps, scale);
+    spell(letter, accidental)
+}
+
+/// Absolute pitch class of `degree` in the key of `root`, spelled so each
+/// degree of `scale` keeps its own letter: b3 over D is F, b3 over E♭ is G♭
+/// and the #4 of B Locrian is F rather than E♯.
+pub fn spell_degree(root: Note, degree: Note, scale: &HashSet<Note>) -> String {
+    let steps = degree_steps(scale);
+    let root = root_spelling(root, &steps, scale);
+    let (letter, accidental) = spell_pitch(root, &steps, degree);
+    spell(letter, accidental)
+}
+
+/// Root of the exercise being played, `None` when nothing is playing.
+pub fn current_root() -> Option<Note> {
+    session::current_exercise().map(|exercise| exercise.root)
+}
+
+/// Key name of the exercise being played in `scale`, e.g. "B♭".
+pub fn current_key_name(scale: &HashSet<Note>) -> Option<String> {
+    current_root().map(|root| key_name(root, scale))
+}
+
+/// Parses a comma separated list of degrees, such as "1,b3,5".
+pub fn parse_notes(s: &str) -> Result<HashSet<Note>, String> {
+    s.split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| part.parse())
+        .collect()
+}
+
+#[derive(Hash, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Preset {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    Chromatic,
+}
+
+pub const ALL_PRESETS: [Preset; 13] = [
+    Preset::Major,
+    Preset::NaturalMinor,
+    Preset::HarmonicMinor,
+    Preset::MelodicMinor,
+    Preset::Dorian,
+    Preset::Phrygian,
+    Preset::Lydian,
+    Preset::Mixolydian,
+    Preset::Locrian,
+    Preset::MajorPentatonic,
+    Preset::MinorPentatonic,
+    Preset::Blues,
+    Preset::Chromatic,
+];
+
+impl Preset {
+    pub fn notes(&self) -> HashSet<Note> {
+        use Note::*;
+        let notes: &[Note] = match self {
+            Preset::Major => &[One, Two, Three, Four, Five, Six, Seven],
+            Preset::NaturalMinor => &[One, Two, FlatThree, Four, Five, FlatSix, FlatSeven],
+            Preset::HarmonicMinor => &[One, Two, FlatThree, Four, Five, FlatSix, Seven],
+            Preset::MelodicMinor => &[One, Two, FlatThree, Four, Five, Six, Seven],
+            Preset::Dorian => &[One, Two, FlatThree, Four, Five, Six, FlatSeven],
+            Preset::Phrygian => &[One, FlatTwo, FlatThree, Four, Five, FlatSix, FlatSeven],
+            Preset::Lydian => &[One, Two, Three, SharpFour, Five, Six, Seven],
+            Preset::Mixolydian => &[One, Two, Three, Four, Five, Six, FlatSeven],
+            Preset::Locrian => &[One, FlatTwo, FlatThree, Four, SharpFour, FlatSix, FlatSeven],
+            Preset::MajorPentatonic => &[One, Two, Three, Five, Six],
+            Preset::MinorPentatonic => &[One, FlatThree, Four, Five, FlatSeven],
+            Preset::Blues => &[One, FlatThree, Four, SharpFour, Five, FlatSeven],
+            Preset::Chromatic => &ALL_NOTES,
+        };
+        notes.iter().copied().collect()
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Preset::Major => "Major",
+            Preset::NaturalMinor => "Natural minor",
+            Preset::HarmonicMinor => "Harmonic minor",
+            Preset::MelodicMinor => "Melodic minor",
+            Preset::Dorian => "Dorian",
+            Preset::Phrygian => "Phrygian",
+            Preset::Lydian => "Lydian",
+            Preset::Mixolydian => "Mixolydian",
+            Preset::Locrian => "Locrian",
+            Preset::MajorPentatonic => "Major pentatonic",
+            Preset::MinorPentatonic => "Minor pentatonic",
+            Preset::Blues => "Blues",
+            Preset::Chromatic => "Chromatic",
+        }
+        .to_string()
+    }
+}
+
+pub fn all_presets() -> Vec<Preset> {
+    ALL_PRESETS.to_vec()
+}
+
+lazy_static! {
+    static ref CUSTOM_PRESETS: Mutex<BTreeMap<String, HashSet<Note>>> = Mutex::new(BTreeMap::new());
+}
+
+/// Formats degrees in scale order, the inverse of `parse_notes`.
+pub fn format_notes(notes: &HashSet<Note>) -> String {
+    ALL_NOTES
+        .iter()
+        .filter(|note| notes.contains(note))
+        .map(|note| note.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The trimmed name, if `name` and `notes` make a valid custom preset.
+fn check_custom_preset(name: &str, notes: &HashSet<Note>) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() || name.contains([':', '\n']) {
+        return Err(format!("Invalid preset name: {:?}", name));
+    }
+    if notes.is_empty() {
+        return Err("A preset needs at least one note".to_string());
+    }
+    Ok(name.to_string())
+}
+
+/// Stores a named degree set, replacing any preset with the same name.
+pub fn save_custom_preset(name: String, notes: HashSet<Note>) -> Result<(), String> {
+    let name = check_custom_preset(&name, &notes)?;
+    CUSTOM_PRESETS.lock().unwrap().insert(name, notes);
+    Ok(())
+}
+
+pub fn custom_preset(name: String) -> Option<HashSet<Note>> {
+    CUSTOM_PRESETS.lock().unwrap().get(name.trim()).cloned()
+}
+
+pub fn delete_custom_preset(name: String) -> bool {
+    CUSTOM_PRESETS.lock().unwrap().remove(name.trim()).is_some()
+}
+
+pub fn custom_preset_names() -> Vec<String> {
+    CUSTOM_PRESETS.lock().unwrap().keys().cloned().collect()
+}
+
+/// Serializes the custom presets as "name: 1,b3,5" lines, for the app to persist.
+pub fn export_custom_presets() -> String {
+    CUSTOM_PRESETS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(name, notes)| format!("{}: {}\n", name, format_notes(notes)))
+        .collect()
+}
+
+/// Loads presets written by `export_custom_presets`, keeping existing ones.
+///
+/// Nothing is stored unless every line is a valid preset.
+pub fn import_custom_presets(data: String) -> Result<(), String> {
+    let mut presets = Vec::new();
+    for line in data.lines().filter(|line| !line.trim().is_empty()) {
+        let (name, notes) = line
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid preset line: {}", line))?;
+        let notes = parse_notes(notes)?;
+        presets.push((check_custom_preset(name, &notes)?, notes));
+    }
+    CUSTOM_PRESETS.lock().unwrap().extend(presets);
+    Ok(())
+}
+
+pub const ALL_NOTES: [Note; 12] = [
+    Note::One,
+    Note::FlatTwo,
+    Note::Two,
+    Note::FlatThree,
+    Note::Three,
+    Note::Four,
+    Note::SharpFour,
+    Note::Five,
+    Note::FlatSix,
+    Note::Six,
+    Note::FlatSeven,
+    Note::Seven,
+];
+
 // Expose a function that returns all notes
 pub fn get_all_notes() -> HashSet<Note> {
-    HashSet::from([
-        Note::One,
-        Note::FlatTwo,
-        Note::Two,
-        Note::FlatThree,
-        Note::Three,
-        Note::Four,
-        Note::SharpFour,
-        Note::Five,
-        Note::FlatSix,
-        Note::Six,
-        Note::FlatSeven,
-        Note::Seven,
-    ])
+    HashSet::from(ALL_NOTES)
 }
 
 pub fn all_notes() -> Vec<Note> {
-    vec![
-        Note::One,
-        Note::FlatTwo,
-        Note::Two,
-        Note::FlatThree,
-        Note::Three,
-        Note::Four,
-        Note::SharpFour,
-        Note::Five,
-        Note::FlatSix,
-        Note::Six,
-        Note::FlatSeven,
-        Note::Seven,
-    ]
+    ALL_NOTES.to_vec()
 }
 pub fn stop() {}
 pub fn play_exercise() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_note_numeric_mappings_agree() {
+        for (index, note) in ALL_NOTES.into_iter().enumerate() {
+            assert_eq!(index as u8, note.semitones(), "{}", note);
+            assert_eq!(note as u8, note.semitones(), "{}", note);
+            assert_eq!(Ok(note), Note::try_from(note.semitones()), "{}", note);
+            assert_eq!(note, Note::from_number(note.semitones() as i32), "{}", note);
+            assert_eq!(
+                note.semitones() as i32 + 1,
+                note.to_keyboard_note(),
+                "{}",
+                note
+            );
+        }
+        assert_eq!(all_notes(), ALL_NOTES.to_vec());
+        assert!(Note::try_from(12).is_err());
+    }
+
+    #[test]
+    fn test_note_display_round_trip() {
+        for note in ALL_NOTES {
+            assert_eq!(Ok(note), note.to_string().parse::<Note>(), "{}", note);
+        }
+    }
+
+    #[test]
+    fn test_note_from_str_enharmonics() {
+        let cases = [
+            ("#1", Note::FlatTwo),
+            ("#2", Note::FlatThree),
+            ("b4", Note::Three),
+            ("#3", Note::Four),
+            ("b5", Note::SharpFour),
+            ("#5", Note::FlatSix),
+            ("#6", Note::FlatSeven),
+            ("bb7", Note::Six),
+            ("#7", Note::One),
+            ("b1", Note::Seven),
+            ("\u{266d}3", Note::FlatThree),
+            ("\u{266f}4", Note::SharpFour),
+            (" 5 ", Note::Five),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(Ok(expected), input.parse::<Note>(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_note_from_str_solfege() {
+        let syllables = [
+            "do", "ra", "re", "me", "mi", "fa", "fi", "sol", "le", "la", "te", "ti",
+        ];
+        for (syllable, note) in syllables.iter().zip(ALL_NOTES) {
+            assert_eq!(Ok(note), syllable.parse::<Note>(), "{}", syllable);
+        }
+        assert_eq!(Ok(Note::FlatTwo), "di".parse::<Note>());
+        assert_eq!(Ok(Note::FlatThree), "ri".parse::<Note>());
+        assert_eq!(Ok(Note::SharpFour), "se".parse::<Note>());
+        assert_eq!(Ok(Note::FlatSix), "si".parse::<Note>());
+        assert_eq!(Ok(Note::FlatSeven), "li".parse::<Note>());
+        assert_eq!(Ok(Note::Five), "So".parse::<Note>());
+    }
+
+    #[test]
+    fn test_note_from_str_intervals() {
+        let intervals = [
+            "P1", "m2", "M2", "m3", "M3", "P4", "TT", "P5", "m6", "M6", "m7", "M7",
+        ];
+        for (interval, note) in intervals.iter().zip(ALL_NOTES) {
+            assert_eq!(Ok(note), interval.parse::<Note>(), "{}", interval);
+        }
+        assert_eq!(Ok(Note::SharpFour), "A4".parse::<Note>());
+        assert_eq!(Ok(Note::SharpFour), "d5".parse::<Note>());
+        assert_eq!(Ok(Note::FlatSix), "A5".parse::<Note>());
+        assert_eq!(Ok(Note::One), "P8".parse::<Note>());
+    }
+
+    #[test]
+    fn test_note_from_str_rejects_unknown() {
+        for input in ["", "8", "0", "b", "x3", "mm3", "dore"] {
+            assert!(input.parse::<Note>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_key_name() {
+        let names = [
+            "C",
+            "D\u{266d}",
+            "D",
+            "E\u{266d}",
+            "E",
+            "F",
+            "F\u{266f}",
+            "G",
+            "A\u{266d}",
+            "A",
+            "B\u{266d}",
+            "B",
+        ];
+        for (root, name) in ALL_NOTES.into_iter().zip(names) {
+            assert_eq!(name, key_name(root, &Preset::Major.notes()));
+        }
+    }
+
+    #[test]
+    fn test_minor_key_name() {
+        let names = [
+            "C",
+            "C\u{266f}",
+            "D",
+            "E\u{266d}",
+            "E",
+            "F",
+            "F\u{266f}",
+            "G",
+            "G\u{266f}",
+            "A",
+            "B\u{266d}",
+            "B",
+        ];
+        for (root, name) in ALL_NOTES.into_iter().zip(names) {
+            assert_eq!(name, key_name(root, &Preset::NaturalMinor.notes()));
+        }
+    }
+
+    #[test]
+    fn test_spell_degree() {
+        let major = Preset::Major.notes();
+        assert_eq!("F", spell_degree(Note::Two, Note::FlatThree, &major));
+        assert_eq!(
+            "G\u{266d}",
+            spell_degree(Note::FlatThree, Note::FlatThree, &major)
+        );
+        assert_eq!("G", spell_degree(Note::FlatSeven, Note::Six, &major));
+        assert_eq!("F\u{266f}", spell_degree(Note::Two, Note::Three, &major));
+        assert_eq!(
+            "E\u{266f}",
+            spell_degree(Note::SharpFour, Note::Seven, &major)
+        );
+        assert_eq!("A", spell_degree(Note::SharpFour, Note::FlatThree, &major));
+        assert_eq!(
+            "C\u{266d}",
+            spell_degree(Note::FlatThree, Note::FlatSix, &major)
+        );
+        assert_eq!(
+            "B\u{1d12b}",
+            spell_degree(Note::FlatTwo, Note::FlatSix, &major)
+        );
+        assert_eq!("B", spell_degree(Note::Five, Note::Three, &major));
+    }
+
+    #[test]
+    fn test_spell_degree_in_minor() {
+        let natural = Preset::NaturalMinor.notes();
+        assert_eq!(
+            "B\u{266d}",
+            spell_degree(Note::Two, Note::FlatSix, &natural)
+        );
+        assert_eq!("E", spell_degree(Note::FlatTwo, Note::FlatThree, &natural));
+        assert_eq!("A", spell_degree(Note::FlatTwo, Note::FlatSix, &natural));
+        assert_eq!("B", spell_degree(Note::FlatSix, Note::FlatThree, &natural));
+        assert_eq!(
+            "G\u{266d}",
+            spell_degree(Note::FlatThree, Note::FlatThree, &natural)
+        );
+
+        let harmonic = Preset::HarmonicMinor.notes();
+        assert_eq!(
+            "B\u{266f}",
+            spell_degree(Note::FlatTwo, Note::Seven, &harmonic)
+        );
+        assert_eq!(
+            "F\u{266f}",
+            spell_degree(Note::Five, Note::Seven, &harmonic)
+        );
+
+        let locrian = Preset::Locrian.notes();
+        assert_eq!(
+            "G\u{266d}",
+            spell_degree(Note::One, Note::SharpFour, &locrian)
+        );
+        assert_eq!("F", spell_degree(Note::Seven, Note::SharpFour, &locrian));
+    }
+
+    #[test]
+    fn test_seven_note_scales_use_every_letter() {
+        for preset in ALL_PRESETS {
+            let scale = preset.notes();
+            if scale.len() != 7 {
+                continue;
+            }
+            for root in ALL_NOTES {
+                let letters: HashSet<char> = scale
+                    .iter()
+                    .map(|&degree| spell_degree(root, degree, &scale).chars().next().unwrap())
+                    .collect();
+                assert_eq!(7, letters.len(), "{} over {:?}", preset.name(), root);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spell_degree_matches_pitch_class() {
+        let pitch_classes = [
+            ('C', 0),
+            ('D', 2),
+            ('E', 4),
+            ('F', 5),
+            ('G', 7),
+            ('A', 9),
+            ('B', 11),
+        ];
+        for preset in ALL_PRESETS {
+            let scale = preset.notes();
+            for root in ALL_NOTES {
+                for degree in ALL_NOTES {
+                    let name = spell_degree(root, degree, &scale);
+                    let mut chars = name.chars();
+                    let letter = chars.next().unwrap();
+                    let natural = pitch_classes.iter().find(|(l, _)| *l == letter).unwrap().1;
+                    let accidental: i32 = chars
+                        .map(|c| match c {
+                            '\u{266d}' => -1,
+                            '\u{266f}' => 1,
+                            '\u{1d12b}' => -2,
+                            '\u{1d12a}' => 2,
+                            _ => panic!("unexpected {}", c),
+                        })
+                        .sum();
+                    assert_eq!(
+                        (root.semitones() + degree.semitones()) as i32 % 12,
+                        (natural + accidental).rem_euclid(12),
+                        "{} over {} in {}",
+                        degree,
+                        key_name(root, &scale),
+                        preset.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(parse_notes("1,2,b3,4,5,6,b7"), Ok(Preset::Dorian.notes()));
+        assert_eq!(parse_notes("1,b3,4,#4,5,b7"), Ok(Preset::Blues.notes()));
+        assert_eq!(get_all_notes(), Preset::Chromatic.notes());
+        for preset in ALL_PRESETS {
+            assert!(preset.notes().contains(&Note::One), "{}", preset.name());
+        }
+    }
+
+    #[test]
+    fn test_custom_presets_round_trip() {
+        let notes = HashSet::from([Note::One, Note::FlatThree, Note::SharpFour]);
+        save_custom_preset(" test round trip ".to_string(), notes.clone()).unwrap();
+        assert_eq!(
+            Some(notes.clone()),
+            custom_preset("test round trip".to_string())
+        );
+
+        let exported = export_custom_presets();
+        assert!(exported.contains("test round trip: 1,b3,#4\n"));
+
+        assert!(delete_custom_preset("test round trip".to_string()));
+        assert_eq!(None, custom_preset("test round trip".to_string()));
+
+        import_custom_presets(exported).unwrap();
+        assert_eq!(Some(notes), custom_preset("test round trip".to_string()));
+        assert!(custom_preset_names().contains(&"test round trip".to_string()));
+    }
+
+    #[test]
+    fn test_custom_presets_rejects_invalid() {
+        assert!(save_custom_preset("".to_string(), Preset::Major.notes()).is_err());
+        assert!(save_custom_preset("a:b".to_string(), Preset::Major.notes()).is_err());
+        assert!(save_custom_preset("empty".to_string(), HashSet::new()).is_err());
+        assert!(import_custom_presets("no separator".to_string()).is_err());
+        assert!(import_custom_presets("bad: 1,9".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_import_custom_presets_is_all_or_nothing() {
+        for bad_line in ["   : 1,5", "no notes:"] {
+            let data = format!("test partial import: 1,3,5\n{}\n", bad_line);
+            assert!(import_custom_presets(data).is_err(), "{:?}", bad_line);
+            assert!(!custom_preset_names().contains(&"test partial import".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_parse_notes() {
+        assert_eq!(
+            Ok(HashSet::from([Note::One, Note::FlatThree, Note::Five])),
+            parse_notes("1, me,P5")
+        );
+        assert!(parse_notes("1,9").is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use crate::api::degrees::Degree;
+use crate::api::notes::Note;
+use crate::player::quiz::Quiz;
+use crate::player::session;
+
+lazy_static! {
+    static ref QUIZ: Mutex<Quiz> = Mutex::new(Quiz::new());
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnswerOutcome {
+    Correct,
+    Incorrect {
+        expected: Degree,
+    },
+    AlreadyAnswered,
+    /// The voice has already spoken the answer, so it doesn't count.
+    Revealed,
+    NoExercise,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnswerStatus {
+    Correct,
+    Incorrect,
+    AlreadyAnswered,
+    Revealed,
+    NoExercise,
+}
+
+/// `AnswerOutcome` as plain data, the way Dart receives it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AnswerFeedback {
+    pub status: AnswerStatus,
+    /// The degree that was playing, for an incorrect answer.
+    pub expected: Option<Degree>,
+}
+
+impl From<AnswerOutcome> for AnswerFeedback {
+    fn from(outcome: AnswerOutcome) -> Self {
+        let (status, expected) = match outcome {
+            AnswerOutcome::Correct => (AnswerStatus::Correct, None),
+            AnswerOutcome::Incorrect { expected } => (AnswerStatus::Incorrect, Some(expected)),
+            AnswerOutcome::AlreadyAnswered => (AnswerStatus::AlreadyAnswered, None),
+            AnswerOutcome::Revealed => (AnswerStatus::Revealed, None),
+            AnswerOutcome::NoExercise => (AnswerStatus::NoExercise, None),
+        };
+        AnswerFeedback { status, expected }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub correct: u32,
+    pub answered: u32,
+}
+
+/// Checks the answer against the exercise that is playing right now.
+pub fn submit_answer(answer: Note) -> AnswerFeedback {
+    QUIZ.lock()
+        .unwrap()
+        .answer(session::current_exercise(), answer)
+        .into()
+}
+
+/// Same as `submit_answer`, for sessions from `start_playing_degrees`.
+pub fn submit_degree_answer(answer: Degree) -> AnswerFeedback {
+    QUIZ.lock()
+        .unwrap()
+        .answer(session::current_exercise(), answer)
+        .into()
+}
+
+pub fn quiz_score() -> Score {
+    QUIZ.lock().unwrap().score()
+}
+
+pub fn reset_quiz() {
+    *QUIZ.lock().unwrap() = Quiz::new();
+}
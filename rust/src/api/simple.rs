@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use crate::api::degrees::Degree;
 use crate::api::notes::Note;
 use crate::player::manager;
 use crate::player::wav;
@@ -14,6 +15,16 @@ pub fn start_playing(notes: HashSet<Note>) {
     manager::start_playing(notes);
 }
 
+/// Plays a set of microtonal degrees, e.g. from `edo_degrees(24)`, instead
+/// of whole notes.
+pub fn start_playing_degrees(degrees: Vec<Degree>) -> Result<(), String> {
+    if degrees.is_empty() {
+        return Err("Degrees cannot be empty".to_string());
+    }
+    manager::start_playing_degrees(degrees.into_iter().collect());
+    Ok(())
+}
+
 pub fn stop_playing() {
     manager::stop_playing();
 }
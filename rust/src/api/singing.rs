@@ -0,0 +1,115 @@
+use crate::api::degrees::Degree;
+use crate::api::notes::Note;
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::pitch::{detect_pitches, median_pitch};
+use crate::player::wav::WavFile;
+use crate::player::{input, intonation, session, tuning};
+
+/// A sung pitch compared with the degree the exercise asked for.
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SungAnswer {
+    pub frequency: f32,
+    /// The nearest degree above the root, in any octave.
+    pub sung: Note,
+    pub expected: Degree,
+    /// How far the singing is from `expected`, negative when flat.
+    pub cents: f32,
+    /// Within 50 cents of `expected`.
+    pub correct: bool,
+}
+
+/// Starts listening on the default microphone, the drone keeps playing.
+pub fn start_listening() -> Result<(), String> {
+    input::start_listening()
+}
+
+pub fn stop_listening() {
+    input::stop_listening()
+}
+
+pub fn is_listening() -> bool {
+    input::is_listening()
+}
+
+/// What is being sung right now against the exercise that is playing,
+/// `None` while nothing is sung or no exercise is playing.
+pub fn sung_answer() -> Option<SungAnswer> {
+    let exercise = session::current_exercise()?;
+    let pitch = input::latest_pitch()?;
+    Some(intonation::sung_answer(
+        pitch.frequency,
+        exercise.root,
+        exercise.relative,
+        &tuning::current(),
+    ))
+}
+
+/// Same as `sung_answer` for a recording in any voice file format, `None`
+/// when nothing is sung in it.
+pub fn sung_answer_from_recording(
+    data: Vec<u8>,
+    root: Note,
+    expected: Degree,
+) -> Result<Option<SungAnswer>, String> {
+    let recording = WavFile::new(&data)?;
+    let pitch = median_pitch(&detect_pitches(recording.samples(), SAMPLE_RATE));
+    Ok(pitch
+        .map(|pitch| intonation::sung_answer(pitch.frequency, root, expected, &tuning::current())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::exercise_generator::root_note_to_frequency;
+    use hound::{SampleFormat, WavSpec, WavWriter};
+    use std::f32::consts::PI;
+    use std::io::Cursor;
+    use test_log::test;
+
+    // Half a second of a tone with a few harmonics, as a 16 bit 44.1 kHz WAV
+    fn recording(frequency: f32) -> Vec<u8> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut data = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut data, spec).unwrap();
+        for i in 0..spec.sample_rate / 2 {
+            let phase = 2.0 * PI * frequency * i as f32 / spec.sample_rate as f32;
+            let value = 0.4 * phase.sin() + 0.2 * (2.0 * phase).sin() + 0.1 * (3.0 * phase).sin();
+            writer
+                .write_sample((value * i16::MAX as f32) as i16)
+                .unwrap();
+        }
+        writer.finalize().unwrap();
+        data.into_inner()
+    }
+
+    #[test]
+    fn test_sung_answer_from_recording() {
+        // A fifth above a D drone, sung an octave up and 20 cents sharp
+        let fifth = root_note_to_frequency(Note::Two) * 3.0 * 2f32.powf(20.0 / 1200.0);
+        let answer = sung_answer_from_recording(recording(fifth), Note::Two, Note::Five.into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(answer.sung, Note::Five);
+        assert!((answer.cents - 22.0).abs() < 3.0, "{:?}", answer);
+        assert!(answer.correct);
+
+        let answer = sung_answer_from_recording(recording(fifth), Note::Two, Note::Six.into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(answer.sung, Note::Five);
+        assert!(!answer.correct);
+    }
+
+    #[test]
+    fn test_silent_recording_has_no_answer() {
+        let answer = sung_answer_from_recording(recording(0.0), Note::One, Note::One.into());
+        assert_eq!(answer, Ok(None));
+        assert!(sung_answer_from_recording(vec![1, 2, 3], Note::One, Note::One.into()).is_err());
+    }
+}
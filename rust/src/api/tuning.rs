@@ -0,0 +1,23 @@
+use crate::player::tuning;
+
+#[derive(Hash, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TuningSystem {
+    EqualTemperament,
+    JustIntonation,
+    Pythagorean,
+}
+
+/// Selects the tuning used from the next exercise on.
+///
+/// `reference_pitch` is the frequency of A4 in Hz, e.g. 440, 442, 432 or 415.
+pub fn set_tuning(system: TuningSystem, reference_pitch: f32) -> Result<(), String> {
+    tuning::set_tuning(system, reference_pitch)
+}
+
+pub fn tuning_system() -> TuningSystem {
+    tuning::current().system
+}
+
+pub fn reference_pitch() -> f32 {
+    tuning::current().reference_pitch
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::player::playback;
+use crate::player::speech;
+use crate::player::wav;
+
+/// Loads a voice pack from a directory with a `manifest.txt` of
+/// `degree: file` lines, or with files named `1.wav` to `12.wav`.
+pub fn load_voice_pack_from_dir(name: String, path: String) -> Result<(), String> {
+    wav::load_voice_pack_from_dir(&name, Path::new(&path))
+}
+
+/// Loads a voice pack from a manifest and the bytes of each file it names.
+pub fn load_voice_pack_from_bundle(
+    name: String,
+    manifest: String,
+    files: HashMap<String, Vec<u8>>,
+) -> Result<(), String> {
+    wav::load_voice_pack_from_bundle(&name, &manifest, &files)
+}
+
+/// Answers with the given pack from the next `start_playing` on.
+pub fn set_voice_pack(name: String) -> Result<(), String> {
+    wav::set_active_voice_pack(&name)
+}
+
+pub fn voice_pack() -> Result<String, String> {
+    wav::active_voice_pack()
+}
+
+pub fn voice_packs() -> Result<Vec<String>, String> {
+    wav::voice_pack_names()
+}
+
+/// Speaks `text` with the built-in synthesizer, mono samples at 48 kHz.
+pub fn speak(text: String) -> Result<Vec<f32>, String> {
+    speech::speak(&text)
+}
+
+/// Loads the default voices from a directory, see `load_voice_pack_from_dir`.
+pub fn init_voices_from_dir(path: String) -> Result<(), String> {
+    wav::init_voices_from_dir(Path::new(&path))
+}
+
+/// Loads the default voices from the contents of their files, in degree
+/// order 1, b2, 2 and so on.
+pub fn init_voices_from_bytes(wav_data: Vec<Vec<u8>>) -> Result<(), String> {
+    wav::init_voices_from_bytes(&wav_data)
+}
+
+/// False until recorded voices are loaded, the synthesizer speaks until then.
+pub fn voices_loaded() -> bool {
+    wav::voices_loaded()
+}
+
+/// Speeds up or slows down the voice answers by `tempo`, 0.5 to 2, and shifts
+/// them by `pitch` semitones, -12 to 12, from the next `start_playing` on.
+pub fn set_voice_style(tempo: f32, pitch: f32) -> Result<(), String> {
+    playback::set_voice_style(tempo, pitch)
+}
+
+pub fn voice_tempo() -> f32 {
+    playback::voice_style().tempo
+}
+
+pub fn voice_pitch() -> f32 {
+    playback::voice_style().pitch
+}
@@ -1,15 +1,398 @@
-pub mod api;
-pub mod player;
-
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use cpal::traits::{DeviceTrait, HostTrait};
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use rust_lib_tonestep::api::degrees::{edo_degrees, parse_degrees, Degree};
+use rust_lib_tonestep::api::notes::{get_all_notes, key_name, parse_notes, ALL_NOTES, ALL_PRESETS};
+use rust_lib_tonestep::api::quiz::AnswerOutcome;
+use rust_lib_tonestep::player::constants::{ROOT_END_TIME, SAMPLE_RATE};
+use rust_lib_tonestep::player::playback::{set_voice_style, voice_style, VoiceStyle};
+use rust_lib_tonestep::player::quiz::Quiz;
+use rust_lib_tonestep::player::session;
+use rust_lib_tonestep::player::sink::MemorySink;
+use rust_lib_tonestep::player::speech::speak;
+use rust_lib_tonestep::player::wav::{
+    active_voice_pack, init_voices_from_dir, load_voice_pack_from_dir, set_active_voice_pack,
+    voice_bank, voices_loaded,
+};
+use rust_lib_tonestep::player::PlayOptions;
+use rust_lib_tonestep::player::{input, intonation, manager};
+
+const USAGE: &str = "\
+Usage: tonestep <command> [options]
+
+Commands:
+  play               Play exercises until Enter is pressed
+  quiz               Play exercises and type the degree you hear
+  meter              Play exercises and show how in tune you sing
+  render <file.wav>  Render exercises to a WAV file
+  say <text> <file.wav>
+                     Speak text with the built-in voice, e.g. flat six
+  devices            List audio output devices
+  stats              Show loaded voices and exercise timings (alias: info)
+
+Options for play, quiz, meter and render:
+  --notes <list>     Degrees to practice, e.g. 1,b3,5 (default: all)
+  --preset <name>    Degrees of a scale, e.g. dorian or minor-pentatonic
+  --degrees <list>   Microtonal degrees, e.g. 1,b3+,5,1050c (b3+ is 350 cents)
+  --edo <n>          All degrees of n equal divisions of the octave, e.g. 24
+  --reps <n>         Exercises per root before it changes (default: 8)
+  --seed <n>         Seed for a repeatable sequence of exercises
+  --timing <speed>   slow, normal or fast (default: normal)
+  --voices <dir>     Answer with the voice pack in this directory
+  --voice-tempo <x>  Speed of the voice answers, 0.5 to 2 (default: 1)
+  --voice-pitch <n>  Shift the voice answers by n semitones, -12 to 12
+  --seconds <n>      Stop after this many seconds
+
+The recorded voices are built in with the embedded-assets feature (the default).
+Voices in $TONESTEP_RESOURCES, or ./resources when it exists, replace them and
+degrees without a recording are spoken by the built-in voice.";
+
+const RESOURCES_VAR: &str = "TONESTEP_RESOURCES";
+
+struct Options {
+    play: PlayOptions,
+    seconds: Option<f32>,
+    // Applied by `apply_voice_options` once every option has parsed
+    voices: Option<PathBuf>,
+    voice_style: VoiceStyle,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut play = PlayOptions::new(get_all_notes());
+    let mut seconds: Option<f32> = None;
+    let mut voices: Option<PathBuf> = None;
+    let mut voice_tempo = voice_style().tempo;
+    let mut voice_pitch = voice_style().pitch;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--notes" => play.notes = parse_notes(value()?)?,
+            "--preset" => {
+                let name = value()?;
+                play.notes = ALL_PRESETS
+                    .iter()
+                    .find(|preset| preset.name().to_lowercase().replace(' ', "-") == *name)
+                    .ok_or_else(|| format!("Unknown preset: {}", name))?
+                    .notes()
+            }
+            "--degrees" => play.degrees = Some(parse_degrees(value()?)?),
+            "--edo" => {
+                let divisions = value()?
+                    .parse()
+                    .map_err(|_| "--edo must be a number".to_string())?;
+                play.degrees = Some(edo_degrees(divisions)?.into_iter().collect())
+            }
+            "--reps" => {
+                play.repetitions = value()?
+                    .parse()
+                    .map_err(|_| "--reps must be a number between 1 and 255".to_string())?
+            }
+            "--seed" => {
+                play.seed = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| "--seed must be a number".to_string())?,
+                )
+            }
+            "--timing" => play.timing = value()?.parse()?,
+            "--voices" => voices = Some(PathBuf::from(value()?)),
+            "--voice-tempo" => {
+                voice_tempo = value()?
+                    .parse()
+                    .map_err(|_| "--voice-tempo must be a number".to_string())?
+            }
+            "--voice-pitch" => {
+                voice_pitch = value()?
+                    .parse()
+                    .map_err(|_| "--voice-pitch must be a number".to_string())?
+            }
+            "--seconds" => {
+                seconds = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| "--seconds must be a number".to_string())?,
+                )
+            }
+            _ => return Err(format!("Unknown option: {}", arg)),
+        }
+    }
+
+    if play.notes.is_empty() {
+        return Err("--notes cannot be empty".to_string());
+    }
+    if play
+        .degrees
+        .as_ref()
+        .is_some_and(|degrees| degrees.is_empty())
+    {
+        return Err("--degrees cannot be empty".to_string());
+    }
+    if play.repetitions == 0 {
+        return Err("--reps must be at least 1".to_string());
+    }
+    if let Some(seconds) = seconds {
+        if !seconds.is_finite() || seconds <= 0.0 {
+            return Err("--seconds must be a positive number".to_string());
+        }
+        // Checked here so the commands can sleep for it without panicking
+        Duration::try_from_secs_f32(seconds).map_err(|_| "--seconds is too large".to_string())?;
+    }
+
+    Ok(Options {
+        play,
+        seconds,
+        voices,
+        voice_style: VoiceStyle::new(voice_tempo, voice_pitch)?,
+    })
+}
+
+/// Selects the voices the options ask for, only for commands that play them.
+fn apply_voice_options(options: &Options) -> Result<(), String> {
+    init_voices()?;
+    if let Some(dir) = &options.voices {
+        let name = dir.display().to_string();
+        load_voice_pack_from_dir(&name, dir)?;
+        set_active_voice_pack(&name)?;
+    }
+    set_voice_style(options.voice_style.tempo, options.voice_style.pitch)
+}
+
+fn play(options: Options) -> Result<(), String> {
+    manager::start_playing_with(options.play);
+
+    match options.seconds {
+        Some(seconds) => thread::sleep(Duration::from_secs_f32(seconds)),
+        None => {
+            println!("Playing, press Enter to stop");
+            let mut line = String::new();
+            let _ = std::io::stdin().lock().read_line(&mut line);
+        }
+    }
+
+    manager::stop_playing();
+    Ok(())
+}
+
+fn quiz(options: Options) -> Result<(), String> {
+    let mut quiz = Quiz::new();
+    manager::start_playing_with(options.play);
+
+    println!("Type the degree you hear (e.g. b3, #4, 5, b3+) and press Enter, q to quit");
+    for line in std::io::stdin().lock().lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        let input = line.trim();
+        if input.is_empty() {
+            continue;
+        }
+        if input == "q" {
+            break;
+        }
+
+        let answer: Degree = match input.parse() {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+        match quiz.answer(session::current_exercise(), answer) {
+            AnswerOutcome::Correct => println!("Correct!"),
+            AnswerOutcome::Incorrect { expected } => println!("Wrong, it was {}", expected),
+            AnswerOutcome::AlreadyAnswered => {
+                println!("Already answered, wait for the next exercise");
+                continue;
+            }
+            AnswerOutcome::Revealed => {
+                println!("Too late, the answer was already spoken");
+                continue;
+            }
+            AnswerOutcome::NoExercise => {
+                println!("Nothing is playing yet");
+                continue;
+            }
+        }
+        let score = quiz.score();
+        println!("Score: {}/{}", score.correct, score.answered);
+    }
+
+    manager::stop_playing();
+    Ok(())
+}
 
-use crate::api::notes::get_all_notes;
-use crate::api::simple::{start_playing, stop_playing};
+fn meter(options: Options) -> Result<(), String> {
+    input::start_listening()?;
+    let scale = options.play.notes.clone();
+    manager::start_playing_with(options.play);
+    let start = Instant::now();
+
+    println!("Sing along with the drone, press Ctrl+C to stop");
+    while options
+        .seconds
+        .is_none_or(|seconds| start.elapsed().as_secs_f32() < seconds)
+    {
+        thread::sleep(Duration::from_millis(250));
+        let readings = intonation::drain_readings();
+        let Some(reading) = readings.last() else {
+            continue;
+        };
+        let Some(exercise) = session::current_exercise() else {
+            continue;
+        };
+        println!(
+            "{:>7.1} Hz  {:>3} in {}  {:+5.0} cents",
+            reading.frequency,
+            reading.note.to_string(),
+            key_name(exercise.root, &scale),
+            reading.cents
+        );
+    }
+
+    manager::stop_playing();
+    input::stop_listening();
+    Ok(())
+}
+
+fn render(path: &str, options: Options) -> Result<(), String> {
+    let seconds = options
+        .seconds
+        .unwrap_or(ROOT_END_TIME as f32 / options.play.timing.speed() as f32);
+
+    let sink = MemorySink::new();
+    manager::set_audio_sink(Box::new(sink.clone()));
+    manager::start_playing_with(options.play);
+    let data = sink.pull_seconds(seconds);
+    manager::stop_playing();
+
+    write_wav(path, 2, &data)?;
+    println!("Rendered {:.1}s to {}", seconds, path);
+    Ok(())
+}
+
+fn say(text: &str, path: &str) -> Result<(), String> {
+    let data = speak(text)?;
+    write_wav(path, 1, &data)?;
+    println!(
+        "Said {:?} in {:.2}s to {}",
+        text,
+        data.len() as f32 / SAMPLE_RATE as f32,
+        path
+    );
+    Ok(())
+}
+
+fn write_wav(path: &str, channels: u16, data: &[f32]) -> Result<(), String> {
+    let spec = WavSpec {
+        channels,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+    };
+    let mut writer = WavWriter::create(path, spec).map_err(|e| e.to_string())?;
+    for sample in data {
+        writer
+            .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .map_err(|e| e.to_string())?;
+    }
+    writer.finalize().map_err(|e| e.to_string())
+}
+
+fn devices() -> Result<(), String> {
+    let host = cpal::default_host();
+    let default_name = host.default_output_device().and_then(|d| d.name().ok());
+
+    let devices = host.output_devices().map_err(|e| e.to_string())?;
+    for device in devices {
+        let name = device.name().unwrap_or_else(|_| "<unknown>".to_string());
+        let marker = if Some(&name) == default_name.as_ref() {
+            "*"
+        } else {
+            " "
+        };
+        println!("{} {}", marker, name);
+    }
+    Ok(())
+}
+
+fn stats() -> Result<(), String> {
+    let voices = voice_bank()?;
+
+    println!("Sample rate: {} Hz", SAMPLE_RATE);
+    println!("Exercise length: {}s at normal timing", ROOT_END_TIME);
+    println!("Voices ({} pack):", active_voice_pack()?);
+    if !voices_loaded() {
+        println!("  No recordings loaded, using the built-in voice");
+    }
+    for note in ALL_NOTES {
+        match voices.duration(note.to_keyboard_note()) {
+            Some(seconds) => println!("  {:>3}  {:.2}s", note.to_string(), seconds),
+            None => println!("  {:>3}  missing", note.to_string()),
+        }
+    }
+    Ok(())
+}
+
+fn init_voices() -> Result<(), String> {
+    match std::env::var(RESOURCES_VAR) {
+        Ok(dir) => init_voices_from_dir(Path::new(&dir)),
+        Err(_) if Path::new("resources").is_dir() => init_voices_from_dir(Path::new("resources")),
+        Err(_) => Ok(()),
+    }
+}
+
+fn playing_options(args: &[String]) -> Result<Options, String> {
+    let options = parse_options(args)?;
+    apply_voice_options(&options)?;
+    Ok(options)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let command = args.first().map(String::as_str);
+
+    match command {
+        Some("play") => play(playing_options(&args[1..])?),
+        Some("quiz") => quiz(playing_options(&args[1..])?),
+        Some("meter") => meter(playing_options(&args[1..])?),
+        Some("render") => {
+            let path = args.get(1).ok_or("render needs an output file")?;
+            render(path, playing_options(&args[2..])?)
+        }
+        Some("say") => match &args[1..] {
+            [text, path] => say(text, path),
+            _ => Err("say needs some text and an output file".to_string()),
+        },
+        Some(command) if args.len() > 1 && ["devices", "stats", "info"].contains(&command) => {
+            Err(format!("{} takes no options", command))
+        }
+        Some("devices") => devices(),
+        Some("stats") | Some("info") => {
+            init_voices()?;
+            stats()
+        }
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!("Unknown command: {}", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
 
 fn main() {
-    start_playing(get_all_notes());
-    thread::sleep(Duration::from_millis(40000));
-    stop_playing();
-    thread::sleep(Duration::from_millis(3000));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("tonestep: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    }
 }
@@ -0,0 +1,22 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// An `f32` that can be shared between the API and the audio thread without locking.
+pub struct AtomicF32 {
+    bits: AtomicU32,
+}
+
+impl AtomicF32 {
+    pub const fn new(value: f32) -> Self {
+        AtomicF32 {
+            bits: AtomicU32::new(value.to_bits()),
+        }
+    }
+
+    pub fn load(&self) -> f32 {
+        f32::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    pub fn store(&self, value: f32) {
+        self.bits.store(value.to_bits(), Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::player::constants::SAMPLE_RATE;
+
+const THRESHOLD_DB: f32 = -12.0;
+const RATIO: f32 = 2.0;
+const KNEE_DB: f32 = 6.0;
+const ATTACK_SECONDS: f32 = 0.01;
+const RELEASE_SECONDS: f32 = 0.15;
+
+/// Gentle stereo-linked compressor, run on the master bus before the limiter.
+///
+/// Uses a soft knee so quiet material is left alone and louder layers are
+/// brought closer together instead of hitting the limiter hard.
+pub struct Compressor {
+    attack: f32,
+    release: f32,
+    envelope_db: f32,
+}
+
+impl Compressor {
+    pub fn new() -> Self {
+        Self::with_sample_rate(SAMPLE_RATE)
+    }
+
+    pub fn with_sample_rate(sample_rate: u32) -> Self {
+        Compressor {
+            attack: 1.0 - (-1.0 / (ATTACK_SECONDS * sample_rate as f32)).exp(),
+            release: 1.0 - (-1.0 / (RELEASE_SECONDS * sample_rate as f32)).exp(),
+            envelope_db: 0.0,
+        }
+    }
+
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let peak = left.abs().max(right.abs()).max(1e-6);
+        let reduction_db = gain_reduction_db(20.0 * peak.log10());
+
+        let coefficient = if reduction_db < self.envelope_db {
+            self.attack
+        } else {
+            self.release
+        };
+        self.envelope_db += (reduction_db - self.envelope_db) * coefficient;
+
+        let gain = 10.0_f32.powf(self.envelope_db / 20.0);
+        (left * gain, right * gain)
+    }
+}
+
+impl Default for Compressor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Static curve, returns the (negative) gain change for a level in dBFS
+fn gain_reduction_db(level_db: f32) -> f32 {
+    let over = level_db - THRESHOLD_DB;
+    let slope = 1.0 / RATIO - 1.0;
+
+    if 2.0 * over < -KNEE_DB {
+        0.0
+    } else if 2.0 * over > KNEE_DB {
+        slope * over
+    } else {
+        slope * (over + KNEE_DB / 2.0).powi(2) / (2.0 * KNEE_DB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_gain_curve_is_flat_below_the_knee() {
+        assert_eq!(gain_reduction_db(-60.0), 0.0);
+        assert_eq!(gain_reduction_db(-20.0), 0.0);
+        assert_eq!(gain_reduction_db(THRESHOLD_DB - KNEE_DB / 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_gain_curve_bends_inside_the_knee() {
+        // Halfway into the knee: (1/2 - 1) * 3² / (2 * 6)
+        assert!((gain_reduction_db(THRESHOLD_DB) + 0.375).abs() < 1e-6);
+
+        let knee: Vec<f32> = (0..=12)
+            .map(|i| gain_reduction_db(THRESHOLD_DB - KNEE_DB / 2.0 + i as f32 * 0.5))
+            .collect();
+        assert!(knee.windows(2).all(|w| w[1] < w[0]));
+        // Meets the straight lines on both sides without a step
+        assert!(knee[0].abs() < 1e-6);
+        assert!((knee[12] + KNEE_DB / 2.0 / RATIO).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gain_curve_follows_the_ratio_above_the_knee() {
+        assert!((gain_reduction_db(-6.0) + 3.0).abs() < 1e-6);
+        assert!((gain_reduction_db(0.0) + 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quiet_signal_is_left_alone() {
+        let mut compressor = Compressor::new();
+        for _ in 0..SAMPLE_RATE / 10 {
+            assert_eq!(compressor.process(0.1, -0.1), (0.1, -0.1));
+        }
+    }
+}
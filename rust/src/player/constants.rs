@@ -1,3 +1,10 @@
+pub const SAMPLE_RATE: u32 = 48000;
+
+pub const DEFAULT_REPETITIONS: u8 = 8;
+
+pub const DRONE_AMPLITUDE: f32 = 0.8;
+pub const RELATIVE_AMPLITUDE: f32 = 0.3;
+
 pub const FADE_IN_DURATION: u64 = 2;
 pub const FADE_OUT_DURATION: u64 = 2;
 
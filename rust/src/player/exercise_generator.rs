@@ -1,16 +1,21 @@
-use crate::api::notes::{get_all_notes, Note};
-use rand::prelude::thread_rng;
-use rand::seq::IteratorRandom;
+use crate::api::degrees::{degrees_from_notes, Degree, CENTS_PER_OCTAVE};
+use crate::api::notes::{Note, ALL_NOTES};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use std::collections::HashSet;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::player::constants::{
     FADE_IN_DURATION, FADE_OUT_DURATION, PLAY_VOICE_ANSWER_START_TIME,
     RELATIVE_ANSWER_FADE_IN_START_TIME, RELATIVE_CHALLENGE_FADE_IN_START_TIME,
     RELATIVE_FULL_VOLUME_DURATION, ROOT_END_TIME, ROOT_FADE_IN_START_TIME,
-    ROOT_FULL_VOLUME_DURATION,
+    ROOT_FULL_VOLUME_DURATION, SAMPLE_RATE,
 };
-use crate::player::wav::{get_wav_file, WavFile};
+use crate::player::playback::PlaybackCursor;
+use crate::player::session;
+use crate::player::tuning::{self, Tuning};
+use crate::player::wav::{voice_bank, VoiceBank};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum VolumeInfo {
@@ -22,26 +27,60 @@ pub enum VolumeInfo {
 
 pub struct Exercise {
     root: Note,
-    relative: Note,
-    wav: WavFile,
+    relative: Degree,
+    voice: Option<PlaybackCursor>,
 }
 
 impl Exercise {
-    fn new(root: Note, relative: Note) -> Result<Self, String> {
-        let key = relative.to_keyboard_note(); // Convert relative to a key (integer)
-
-        // First handle the Result from `get_wav_file`, then the Option
-        let wav = get_wav_file(key).map_err(|e| format!("Error loading WAV file: {}", e))?;
-
-        Ok(Exercise {
+    /// Microtonal degrees have no voice and leave the answer unspoken.
+    ///
+    /// Banks from `voice_bank` have every degree, recorded or synthesized.
+    fn new(root: Note, relative: Degree, voices: &VoiceBank) -> Self {
+        let voice = relative.note().and_then(|note| {
+            let key = note.to_keyboard_note(); // Convert relative to a key (integer)
+            voices.cursor(key)
+        });
+
+        Exercise {
             root,
             relative,
-            wav,
-        })
+            voice,
+        }
     }
 
     fn get_next_voice_sample(&mut self) -> Option<f32> {
-        self.wav.get_next_sample()
+        self.voice.as_mut()?.next_sample()
+    }
+}
+
+/// How fast the exercise timeline runs, voice answers keep their own speed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Timing {
+    Slow,
+    Normal,
+    Fast,
+}
+
+impl Timing {
+    pub fn speed(&self) -> f64 {
+        match self {
+            Timing::Slow => 0.75,
+            Timing::Normal => 1.0,
+            Timing::Fast => 1.5,
+        }
+    }
+}
+
+impl std::str::FromStr for Timing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "slow" => Ok(Timing::Slow),
+            "normal" => Ok(Timing::Normal),
+            "fast" => Ok(Timing::Fast),
+            _ => Err(format!("Unknown timing: {}", s)),
+        }
     }
 }
 
@@ -91,27 +130,109 @@ fn calculate_volume_info(elapsed: Duration, timings: &VolumeTimings) -> VolumeIn
     }
 }
 
+/// Picks exercises and tracks their timing.
+///
+/// Time is counted in rendered frames rather than wall clock time, so audio
+/// can be pulled faster or slower than real time and still line up.
+///
+/// Everything the audio callback needs is set up on construction: the degrees are kept
+/// in a `Vec`, the voices come from a `VoiceBank` snapshot and the random
+/// generator is owned, so moving to the next exercise never locks, allocates
+/// or clones sample data.
 pub struct ExerciseGenerator {
-    notes: HashSet<Note>,
+    degrees: Vec<Degree>,
     repetitions: u8,
     current_repetition: u8,
     exercise: Exercise,
-    pub time: Instant,
+    voices: VoiceBank,
+    rng: StdRng,
+    timing: Timing,
+    tuning: Tuning,
+    // Whether the current exercise and its voice answer have been announced
+    // to `session` yet
+    published: bool,
+    revealed: bool,
+    frame: u64,
     pub sample_clock: f32,
 }
 
 impl ExerciseGenerator {
     pub fn new(notes: HashSet<Note>, repetitions: u8) -> Result<ExerciseGenerator, &'static str> {
-        if notes.is_empty() {
+        Self::with_rng(
+            degrees_from_notes(&notes),
+            repetitions,
+            StdRng::from_entropy(),
+            None,
+        )
+    }
+
+    /// Same sequence of exercises for the same seed and notes.
+    pub fn with_seed(
+        notes: HashSet<Note>,
+        repetitions: u8,
+        seed: u64,
+    ) -> Result<ExerciseGenerator, &'static str> {
+        Self::with_rng(
+            degrees_from_notes(&notes),
+            repetitions,
+            StdRng::seed_from_u64(seed),
+            None,
+        )
+    }
+
+    /// Plays any cent based degrees over the drone, e.g. 24-EDO quarter tones.
+    pub fn with_degrees(
+        degrees: HashSet<Degree>,
+        repetitions: u8,
+        seed: Option<u64>,
+    ) -> Result<ExerciseGenerator, &'static str> {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self::with_rng(degrees, repetitions, rng, None)
+    }
+
+    /// Starts with the given exercise, the following ones use `relative` only.
+    pub fn with_exercise(
+        root: Note,
+        relative: Note,
+        repetitions: u8,
+    ) -> Result<ExerciseGenerator, &'static str> {
+        Self::with_rng(
+            HashSet::from([relative.into()]),
+            repetitions,
+            StdRng::seed_from_u64(0),
+            Some(root),
+        )
+    }
+
+    fn with_rng(
+        degrees: HashSet<Degree>,
+        repetitions: u8,
+        mut rng: StdRng,
+        root: Option<Note>,
+    ) -> Result<ExerciseGenerator, &'static str> {
+        if degrees.is_empty() {
             return Err("The set of notes cannot be empty");
         }
-        let time = Instant::now();
-        let exercise = Exercise::new(random_root(), random_relative(notes.clone())).unwrap();
+        // Keep a stable order so a seeded generator is reproducible
+        let mut degrees: Vec<Degree> = degrees.into_iter().collect();
+        degrees.sort();
+        let voices = voice_bank().map_err(|_| "Failed to load the voice files")?;
+        let root = root.unwrap_or_else(|| random_root(&mut rng));
+        let exercise = Exercise::new(root, random_relative(&degrees, &mut rng), &voices);
         Ok(ExerciseGenerator {
-            notes,
+            degrees,
             repetitions,
-            time,
+            frame: 0,
             exercise,
+            voices,
+            rng,
+            timing: Timing::Normal,
+            tuning: tuning::current(),
+            published: false,
+            revealed: false,
             current_repetition: 1,
             sample_clock: 0f32,
         })
@@ -123,11 +244,21 @@ impl ExerciseGenerator {
 
     pub fn increment_sample_clock(&mut self) {
         self.sample_clock += 1.0;
+        self.frame += 1;
+    }
+
+    pub fn set_timing(&mut self, timing: Timing) {
+        self.timing = timing;
+    }
+
+    /// Time since the current exercise started, on the exercise timeline.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_secs_f64(self.frame as f64 / SAMPLE_RATE as f64 * self.timing.speed())
     }
 
     pub fn generate_command(&self) -> ExerciseCommand {
         Self::_generate_command(
-            self.time.elapsed(),
+            self.elapsed(),
             self.current_repetition == 1,
             self.current_repetition == self.repetitions,
         )
@@ -159,7 +290,7 @@ impl ExerciseGenerator {
     }
 
     pub fn root_volume_info(&self) -> VolumeInfo {
-        Self::_root_volume_info(self.time.elapsed())
+        Self::_root_volume_info(self.elapsed())
     }
 
     fn _root_volume_info(elapsed: Duration) -> VolumeInfo {
@@ -168,7 +299,7 @@ impl ExerciseGenerator {
     }
 
     pub fn relative_challenge_volume_info(&self) -> VolumeInfo {
-        Self::_relative_challenge_volume_info(self.time.elapsed())
+        Self::_relative_challenge_volume_info(self.elapsed())
     }
 
     fn _relative_challenge_volume_info(elapsed: Duration) -> VolumeInfo {
@@ -180,7 +311,7 @@ impl ExerciseGenerator {
     }
 
     pub fn relative_answer_volume_info(&self) -> VolumeInfo {
-        Self::_relative_answer_volume_info(self.time.elapsed())
+        Self::_relative_answer_volume_info(self.elapsed())
     }
 
     fn _relative_answer_volume_info(elapsed: Duration) -> VolumeInfo {
@@ -192,21 +323,29 @@ impl ExerciseGenerator {
     }
 
     pub fn root_frequency(&self) -> f32 {
-        root_note_to_frequency(self.exercise.root)
+        self.tuning
+            .root_frequency(root_note_to_frequency(self.exercise.root))
     }
 
     pub fn relative_frequency(&self) -> f32 {
-        relative_note_to_frequency(relative_note_to_absolute(
+        let relative = self.exercise.relative;
+        let equal_tempered = relative_note_to_frequency(relative_note_to_absolute(
             self.exercise.root,
+            relative.note_below(),
+        )) * 2.0_f32
+            .powf(relative.cents_above_note() as f32 / CENTS_PER_OCTAVE as f32);
+        self.tuning.relative_frequency(
+            self.root_frequency(),
+            equal_tempered,
             self.exercise.relative,
-        ))
+        )
     }
 
     pub fn generate(&mut self) {
-        self._generate(self.time.elapsed())
+        self._generate(self.elapsed())
     }
 
-    fn _generate(&mut self, elapsed: Duration) {
+    fn _generate(&mut self, mut elapsed: Duration) {
         if elapsed >= Duration::from_secs(ROOT_END_TIME) {
             if self.current_repetition == self.repetitions {
                 self.exercise = self.next_exercise();
@@ -215,44 +354,58 @@ impl ExerciseGenerator {
                 self.current_repetition += 1;
                 self.exercise = self.next_exercise_keeping_root();
             }
-            self.time = Instant::now();
+            // Tuning changes apply from the next exercise, never mid-tone
+            self.tuning = tuning::current();
+            self.frame = 0;
+            elapsed = Duration::ZERO;
+            self.published = false;
+            self.revealed = false;
+        }
+        // Announced once it is actually played, so building a generator to
+        // render or test with leaves the exercise being answered alone
+        if !self.published {
+            session::publish(self.exercise.root, self.exercise.relative);
+            self.published = true;
+        }
+        // Answers given once the voice has spoken can't count
+        if !self.revealed && elapsed >= Duration::from_secs(PLAY_VOICE_ANSWER_START_TIME) {
+            session::reveal();
+            self.revealed = true;
         }
     }
 
-    pub fn next_exercise(&self) -> Exercise {
-        let mut root = random_root();
+    pub fn next_exercise(&mut self) -> Exercise {
+        let mut root = random_root(&mut self.rng);
         while root == self.exercise.root {
-            root = random_root();
+            root = random_root(&mut self.rng);
         }
-        Exercise::new(root, self.random_relative(false)).unwrap()
+        let relative = self.random_relative(false);
+        Exercise::new(root, relative, &self.voices)
     }
 
-    fn next_exercise_keeping_root(&self) -> Exercise {
-        Exercise::new(self.exercise.root, self.random_relative(true)).unwrap()
+    fn next_exercise_keeping_root(&mut self) -> Exercise {
+        let relative = self.random_relative(true);
+        Exercise::new(self.exercise.root, relative, &self.voices)
     }
 
-    pub fn random_relative(&self, avoid_repetition: bool) -> Note {
-        let mut relative = random_relative(self.notes.clone());
-        if self.notes.len() == 1 || !avoid_repetition {
+    pub fn random_relative(&mut self, avoid_repetition: bool) -> Degree {
+        let mut relative = random_relative(&self.degrees, &mut self.rng);
+        if self.degrees.len() == 1 || !avoid_repetition {
             return relative;
         }
         while relative == self.exercise.relative {
-            relative = random_relative(self.notes.clone());
+            relative = random_relative(&self.degrees, &mut self.rng);
         }
         relative
     }
 }
 
-fn random_root() -> Note {
-    random_relative(get_all_notes())
+fn random_root(rng: &mut impl Rng) -> Note {
+    random_relative(&ALL_NOTES, rng)
 }
 
-fn random_relative(notes: HashSet<Note>) -> Note {
-    let mut rng = thread_rng();
-    *notes
-        .iter()
-        .choose(&mut rng)
-        .expect("notes cannot be empty")
+fn random_relative<T: Copy>(degrees: &[T], rng: &mut impl Rng) -> T {
+    *degrees.choose(rng).expect("notes cannot be empty")
 }
 
 fn generate_piano_frequency(n: i32) -> f32 {
@@ -263,7 +416,7 @@ fn generate_piano_frequency(n: i32) -> f32 {
     a4_frequency * 2.0_f32.powf((n - a4_key) as f32 / 12.0)
 }
 
-fn root_note_to_frequency(note: Note) -> f32 {
+pub(crate) fn root_note_to_frequency(note: Note) -> f32 {
     generate_piano_frequency(note.to_keyboard_c1_note())
 }
 
@@ -272,13 +425,17 @@ fn relative_note_to_frequency(note: Note) -> f32 {
 }
 
 fn relative_note_to_absolute(root: Note, relative: Note) -> Note {
-    let difference = (relative.to_keyboard_note() - root.to_keyboard_note() + 12) % 12; // Using modulo to wrap around
-    Note::from_number(difference)
+    let sum = (root.semitones() + relative.semitones()) % 12; // Using modulo to wrap around
+    Note::try_from(sum).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::degrees::edo_degrees;
+    use crate::api::tuning::TuningSystem;
+    use crate::player::playback::VoiceStyle;
+    use crate::player::wav::VoicePacks;
     use test_log::test;
 
     use crate::player::constants::{
@@ -357,55 +514,205 @@ mod tests {
         );
 
         assert_eq!(
-            Note::FlatSix,
+            Note::Three,
             relative_note_to_absolute(Note::Three, Note::One)
         );
         assert_eq!(
-            Note::Six,
+            Note::Four,
             relative_note_to_absolute(Note::Three, Note::FlatTwo)
         );
         assert_eq!(
-            Note::FlatSeven,
+            Note::SharpFour,
             relative_note_to_absolute(Note::Three, Note::Two)
         );
         assert_eq!(
-            Note::Seven,
+            Note::Five,
             relative_note_to_absolute(Note::Three, Note::FlatThree)
         );
         assert_eq!(
-            Note::One,
+            Note::FlatSix,
             relative_note_to_absolute(Note::Three, Note::Three)
         );
         assert_eq!(
-            Note::FlatTwo,
+            Note::Six,
             relative_note_to_absolute(Note::Three, Note::Four)
         );
         assert_eq!(
-            Note::Two,
+            Note::FlatSeven,
             relative_note_to_absolute(Note::Three, Note::SharpFour)
         );
         assert_eq!(
-            Note::FlatThree,
+            Note::Seven,
             relative_note_to_absolute(Note::Three, Note::Five)
         );
         assert_eq!(
-            Note::Three,
+            Note::One,
             relative_note_to_absolute(Note::Three, Note::FlatSix)
         );
         assert_eq!(
-            Note::Four,
+            Note::FlatTwo,
             relative_note_to_absolute(Note::Three, Note::Six)
         );
         assert_eq!(
-            Note::SharpFour,
+            Note::Two,
             relative_note_to_absolute(Note::Three, Note::FlatSeven)
         );
         assert_eq!(
-            Note::Five,
+            Note::FlatThree,
             relative_note_to_absolute(Note::Three, Note::Seven)
         );
     }
 
+    #[test]
+    fn test_relative_frequency_is_tuned_against_the_drone() {
+        let mut exercise_generator =
+            ExerciseGenerator::with_exercise(Note::FlatSix, Note::Three, 1).unwrap();
+        exercise_generator.tuning = Tuning {
+            system: TuningSystem::JustIntonation,
+            reference_pitch: 440.0,
+        };
+
+        let ratio = exercise_generator.relative_frequency() / exercise_generator.root_frequency();
+        assert!(
+            (ratio - 2.5).abs() < 1e-4,
+            "a just major third an octave above the drone, got {}",
+            ratio
+        );
+    }
+
+    #[test]
+    fn test_tuning_systems_agree_on_the_degree_over_any_root() {
+        for degree in ALL_NOTES {
+            let mut exercise_generator =
+                ExerciseGenerator::with_exercise(Note::Three, degree, 1).unwrap();
+            let equal_tempered = exercise_generator.relative_frequency();
+            let semitones = 12.0 * (equal_tempered / exercise_generator.root_frequency()).log2();
+            assert!(
+                (semitones.rem_euclid(12.0) - degree.semitones() as f32).abs() < 1e-3,
+                "{} is {} semitones above an E drone",
+                degree,
+                semitones
+            );
+
+            for system in [TuningSystem::JustIntonation, TuningSystem::Pythagorean] {
+                exercise_generator.tuning = Tuning {
+                    system,
+                    reference_pitch: 440.0,
+                };
+                let expected = 1200.0 * exercise_generator.tuning.ratio(degree.into()).log2()
+                    - 100.0 * degree.semitones() as f32;
+                let cents =
+                    1200.0 * (exercise_generator.relative_frequency() / equal_tempered).log2();
+                assert!(
+                    (cents - expected).abs() < 0.1,
+                    "{:?} plays {} {} cents from equal temperament, expected {}",
+                    system,
+                    degree,
+                    cents,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_voice_is_spoken() {
+        let voices = VoicePacks::new().voice_bank();
+        let mut exercise = Exercise::new(Note::One, Note::FlatSix.into(), &voices);
+        assert!(exercise.get_next_voice_sample().is_some());
+    }
+
+    #[test]
+    fn test_voice_plays_in_the_bank_style() {
+        let style = VoiceStyle {
+            tempo: 1.0,
+            pitch: 12.0,
+        };
+        let voices = VoicePacks::new().voice_bank().with_style(style);
+        let mut exercise = Exercise::new(Note::One, Note::FlatSix.into(), &voices);
+
+        let mut played = 0;
+        while exercise.get_next_voice_sample().is_some() {
+            played += 1;
+        }
+        let key = Note::FlatSix.to_keyboard_note();
+        let expected = voices.duration(key).unwrap() * SAMPLE_RATE as f32;
+        assert!(
+            (played as f32 - expected).abs() <= 2.0,
+            "played {} samples, expected {}",
+            played,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_quarter_tone_degrees_play_between_notes() {
+        let neutral_third = Degree::new(350).unwrap();
+        let mut exercise_generator =
+            ExerciseGenerator::with_degrees(HashSet::from([neutral_third]), 1, Some(1)).unwrap();
+        exercise_generator.exercise.root = Note::One;
+
+        let flat_three = relative_note_to_frequency(Note::FlatThree);
+        let three = relative_note_to_frequency(Note::Three);
+        let frequency = exercise_generator.relative_frequency();
+        assert!(
+            (frequency - (flat_three * three).sqrt()).abs() < 1e-2,
+            "halfway between b3 and 3, got {}",
+            frequency
+        );
+        assert_eq!(
+            exercise_generator.get_next_voice_sample(),
+            None,
+            "there is no voice for a quarter tone"
+        );
+    }
+
+    #[test]
+    fn test_quarter_tones_sit_above_their_degree_over_any_root() {
+        let quarter_tones = edo_degrees(24)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.note().is_none());
+        for quarter_tone in quarter_tones {
+            let mut exercise_generator =
+                ExerciseGenerator::with_degrees(HashSet::from([quarter_tone]), 1, Some(1)).unwrap();
+            exercise_generator.exercise.root = Note::Three;
+
+            let above_drone = 1200.0
+                * (exercise_generator.relative_frequency() / exercise_generator.root_frequency())
+                    .log2();
+            let above_degree = above_drone.rem_euclid(1200.0)
+                - 100.0 * quarter_tone.note_below().semitones() as f32;
+            assert!(
+                (above_degree - 50.0).abs() < 0.01,
+                "{:?} is {} cents above {} over an E drone",
+                quarter_tone,
+                above_degree,
+                quarter_tone.note_below()
+            );
+        }
+    }
+
+    #[test]
+    fn test_exercise_is_published_and_revealed_when_played() {
+        let mut exercise_generator = ExerciseGenerator::new(HashSet::from([Note::Two]), 1).unwrap();
+        assert!(!exercise_generator.published);
+
+        exercise_generator.generate();
+        assert!(exercise_generator.published);
+        assert!(!exercise_generator.revealed);
+
+        exercise_generator.frame = PLAY_VOICE_ANSWER_START_TIME * SAMPLE_RATE as u64;
+        exercise_generator.generate();
+        assert!(exercise_generator.revealed);
+
+        exercise_generator.frame = ROOT_END_TIME * SAMPLE_RATE as u64;
+        exercise_generator.generate();
+        assert!(exercise_generator.published);
+        assert!(!exercise_generator.revealed);
+        assert_eq!(exercise_generator.frame, 0);
+    }
+
     #[test]
     fn test_exercise_generator_current_exercise_single_repetition() {
         let mut exercise_generator = ExerciseGenerator::new(HashSet::from([Note::Two]), 1).unwrap();
@@ -414,14 +721,14 @@ mod tests {
 
         assert_eq!(
             exercise_generator.exercise.relative,
-            Note::Two,
+            Note::Two.into(),
             "it should pick a note from the selection"
         );
 
-        exercise_generator.time = Instant::now() + Duration::from_secs(ROOT_END_TIME);
+        exercise_generator.frame = ROOT_END_TIME * SAMPLE_RATE as u64;
 
         assert!(
-            exercise_generator.time.duration_since(Instant::now()) > Duration::from_secs(1),
+            exercise_generator.elapsed() > Duration::from_secs(1),
             "making sure that duration is correctly calculated"
         );
 
@@ -434,7 +741,7 @@ mod tests {
         );
 
         assert!(
-            exercise_generator.time.duration_since(Instant::now()) < Duration::from_secs(1),
+            exercise_generator.elapsed() < Duration::from_secs(1),
             "it should reset the timer"
         );
     }
@@ -446,10 +753,10 @@ mod tests {
 
         exercise_generator._generate(Duration::from_secs(1));
 
-        exercise_generator.time = Instant::now() + Duration::from_secs(ROOT_END_TIME);
+        exercise_generator.frame = ROOT_END_TIME * SAMPLE_RATE as u64;
 
         assert!(
-            exercise_generator.time.duration_since(Instant::now()) > Duration::from_secs(1),
+            exercise_generator.elapsed() > Duration::from_secs(1),
             "making sure that duration is correctly calculated"
         );
 
@@ -463,7 +770,7 @@ mod tests {
         );
 
         assert!(
-            exercise_generator.time.duration_since(Instant::now()) < Duration::from_secs(1),
+            exercise_generator.elapsed() < Duration::from_secs(1),
             "it should reset the timer"
         );
 
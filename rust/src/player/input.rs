@@ -0,0 +1,257 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Sample, SampleFormat};
+use lazy_static::lazy_static;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+use crate::player::atomic::AtomicF32;
+use crate::player::intonation;
+use crate::player::pitch::{Pitch, PitchTracker};
+
+lazy_static! {
+    static ref MICROPHONE: Mutex<Option<Microphone>> = Mutex::new(None);
+}
+
+// Latest detection from the microphone, 0 Hz while nothing is sung
+static LATEST_FREQUENCY: AtomicF32 = AtomicF32::new(0.0);
+static LATEST_CONFIDENCE: AtomicF32 = AtomicF32::new(0.0);
+
+/// Captures the default cpal input device as mono samples.
+///
+/// Like `CpalSink`, the stream lives on its own thread until the microphone
+/// is dropped. Capture runs at the device's own rate, which the handler is
+/// built for.
+pub struct Microphone {
+    sender: mpsc::Sender<()>,
+    sample_rate: u32,
+}
+
+impl Microphone {
+    pub fn open<F>(make_handler: impl FnOnce(u32) -> F + Send + 'static) -> Result<Self, String>
+    where
+        F: FnMut(&[f32]) + Send + 'static,
+    {
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let stream = match build_input_stream(make_handler) {
+                Ok((stream, sample_rate)) => {
+                    let _ = ready_tx.send(Ok(sample_rate));
+                    stream
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return;
+                }
+            };
+            let _ = stop_rx.recv().is_ok();
+            drop(stream);
+        });
+
+        let sample_rate = ready_rx
+            .recv()
+            .map_err(|_| "Microphone thread stopped".to_string())??;
+        Ok(Microphone {
+            sender: stop_tx,
+            sample_rate,
+        })
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+impl Drop for Microphone {
+    fn drop(&mut self) {
+        let _ = self.sender.send(());
+    }
+}
+
+fn build_input_stream<F>(make_handler: impl FnOnce(u32) -> F) -> Result<(cpal::Stream, u32), String>
+where
+    F: FnMut(&[f32]) + Send + 'static,
+{
+    let device = cpal::default_host()
+        .default_input_device()
+        .ok_or_else(|| "No input device available".to_string())?;
+    let supported = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to read the input config: {}", e))?;
+    let format = supported.sample_format();
+    let config = supported.config();
+    let sample_rate = config.sample_rate.0;
+    let channels = config.channels as usize;
+    let handler = make_handler(sample_rate);
+
+    let stream = match format {
+        SampleFormat::F32 => build_mono_stream::<f32, F>(&device, &config, channels, handler),
+        SampleFormat::I16 => build_mono_stream::<i16, F>(&device, &config, channels, handler),
+        SampleFormat::U16 => build_mono_stream::<u16, F>(&device, &config, channels, handler),
+    }
+    .map_err(|e| format!("Failed to open the microphone: {}", e))?;
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start the microphone: {}", e))?;
+    Ok((stream, sample_rate))
+}
+
+fn build_mono_stream<T, F>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    channels: usize,
+    handler: F,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: Sample + 'static,
+    F: FnMut(&[f32]) + Send + 'static,
+{
+    let mut handler = mono_handler::<T>(channels, handler);
+    device.build_input_stream(
+        config,
+        move |data: &[T], _| handler(data),
+        |err| eprintln!("Error: {:?}", err),
+    )
+}
+
+// Averages each interleaved frame of device samples into `handler`
+fn mono_handler<T: Sample>(
+    channels: usize,
+    mut handler: impl FnMut(&[f32]) + Send,
+) -> impl FnMut(&[T]) + Send {
+    let mut mono = Vec::new();
+    move |data| {
+        // Grows to the callback size once, then gets reused
+        mono.clear();
+        mono.extend(
+            data.chunks_exact(channels)
+                .map(|frame| frame.iter().map(|s| s.to_f32()).sum::<f32>() / channels as f32),
+        );
+        handler(&mono)
+    }
+}
+
+/// Keeps the latest detection for `latest_pitch` and queues readings for the
+/// intonation meter, whether the samples come from the microphone or from a
+/// recording.
+pub fn pitch_handler(sample_rate: u32) -> impl FnMut(&[f32]) {
+    tracking_handler(sample_rate, |pitch| {
+        if let Some(pitch) = pitch {
+            intonation::record(pitch);
+        }
+        let pitch = pitch.unwrap_or(Pitch {
+            frequency: 0.0,
+            confidence: 0.0,
+        });
+        LATEST_FREQUENCY.store(pitch.frequency);
+        LATEST_CONFIDENCE.store(pitch.confidence);
+    })
+}
+
+fn tracking_handler(
+    sample_rate: u32,
+    mut on_pitch: impl FnMut(Option<Pitch>),
+) -> impl FnMut(&[f32]) {
+    let mut tracker = PitchTracker::new(sample_rate);
+    move |samples| tracker.push(samples, &mut on_pitch)
+}
+
+/// Starts pitch detection on the default input device, replacing any
+/// previous capture.
+pub fn start_listening() -> Result<(), String> {
+    let mut microphone = MICROPHONE.lock().map_err(|e| e.to_string())?;
+    *microphone = None;
+    *microphone = Some(Microphone::open(pitch_handler)?);
+    Ok(())
+}
+
+pub fn stop_listening() {
+    if let Ok(mut microphone) = MICROPHONE.lock() {
+        *microphone = None;
+    }
+    LATEST_FREQUENCY.store(0.0);
+    LATEST_CONFIDENCE.store(0.0);
+    intonation::close_stream();
+}
+
+pub fn is_listening() -> bool {
+    MICROPHONE
+        .lock()
+        .is_ok_and(|microphone| microphone.is_some())
+}
+
+/// The pitch being sung right now, `None` during silence.
+pub fn latest_pitch() -> Option<Pitch> {
+    let frequency = LATEST_FREQUENCY.load();
+    if frequency <= 0.0 {
+        return None;
+    }
+    Some(Pitch {
+        frequency,
+        confidence: LATEST_CONFIDENCE.load(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::notes::Note;
+    use crate::player::constants::SAMPLE_RATE;
+    use crate::player::exercise_generator::root_note_to_frequency;
+    use crate::player::speech::speak;
+    use crate::player::tuning::Tuning;
+    use std::f32::consts::PI;
+    use test_log::test;
+
+    #[test]
+    fn test_pitch_handler_tracks_fed_samples() {
+        // The synthesizer glides from 130 to 95 Hz, standing in for a singer
+        let mut handler = pitch_handler(SAMPLE_RATE);
+        let spoken = speak("la").unwrap();
+        let mut heard = Vec::new();
+        for chunk in spoken.chunks(512) {
+            handler(chunk);
+            heard.extend(latest_pitch());
+        }
+        assert!(!heard.is_empty());
+        assert!(heard
+            .iter()
+            .all(|pitch| (90.0..140.0).contains(&pitch.frequency)));
+
+        handler(&vec![0.0; SAMPLE_RATE as usize / 10]);
+        assert_eq!(latest_pitch(), None);
+    }
+
+    #[test]
+    fn test_stream_handler_turns_device_samples_into_readings() {
+        // A just fifth over the drone on 1, sung into the left channel of a
+        // 16-bit stereo device
+        let tuning = Tuning::default();
+        let fifth = tuning.root_frequency(root_note_to_frequency(Note::One)) * 1.5;
+        let device_samples: Vec<i16> = (0..SAMPLE_RATE as usize / 2)
+            .flat_map(|i| {
+                let phase = 2.0 * PI * fifth * i as f32 / SAMPLE_RATE as f32;
+                [(0.5 * phase.sin() * i16::MAX as f32) as i16, 0]
+            })
+            .collect();
+
+        let mut readings = Vec::new();
+        {
+            let tracker = tracking_handler(SAMPLE_RATE, |pitch| {
+                readings.extend(pitch.map(|pitch| intonation::reading(pitch, Note::One, &tuning)))
+            });
+            let mut handler = mono_handler::<i16>(2, tracker);
+            // Callback sized chunks of interleaved frames
+            for chunk in device_samples.chunks(2 * 480) {
+                handler(chunk);
+            }
+        }
+
+        assert!(readings.len() > 10, "{} readings", readings.len());
+        for reading in readings {
+            assert_eq!(reading.note, Note::Five, "{:?}", reading);
+            assert!((reading.cents - 1.955).abs() < 1.0, "{:?}", reading);
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use lazy_static::lazy_static;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::api::degrees::{Degree, CENTS_PER_OCTAVE};
+use crate::api::intonation::IntonationReading;
+use crate::api::notes::{Note, ALL_NOTES};
+use crate::api::singing::SungAnswer;
+use crate::player::exercise_generator::root_note_to_frequency;
+use crate::player::pitch::Pitch;
+use crate::player::session;
+use crate::player::tuning::{self, Tuning};
+
+/// Sung answers within this many cents of the degree count as correct.
+pub const SUNG_TOLERANCE_CENTS: f32 = 50.0;
+
+// About three seconds of detections, older ones are dropped if nobody drains them
+const MAX_READINGS: usize = 256;
+
+lazy_static! {
+    static ref READINGS: Mutex<ReadingQueue> = Mutex::new(ReadingQueue::default());
+}
+
+// Bumped whenever the reading stream opens or closes, so a stream's worker
+// can tell it has been replaced or stopped
+static STREAM_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+const OCTAVE: f32 = CENTS_PER_OCTAVE as f32;
+
+/// Cents of `frequency` above the drone on `root`, folded into one octave so
+/// any octave of the degree can be sung.
+pub fn cents_above_root(frequency: f32, root: Note, tuning: &Tuning) -> f32 {
+    let drone = tuning.root_frequency(root_note_to_frequency(root));
+    (OCTAVE * (frequency / drone).log2()).rem_euclid(OCTAVE)
+}
+
+/// Signed cents from `degree` as tuned by `tuning` to `cents`, the short way
+/// round the octave, so a sharp root is a few cents above 0 and not 1190.
+pub fn cents_off(cents: f32, degree: Degree, tuning: &Tuning) -> f32 {
+    let target = OCTAVE * tuning.ratio(degree).log2();
+    (cents - target + OCTAVE / 2.0).rem_euclid(OCTAVE) - OCTAVE / 2.0
+}
+
+/// The `Note` nearest to `cents` above the root, and how far off it `cents` is.
+pub fn nearest_note(cents: f32, tuning: &Tuning) -> (Note, f32) {
+    ALL_NOTES
+        .into_iter()
+        .map(|note| (note, cents_off(cents, Degree::from(note), tuning)))
+        .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+        .unwrap()
+}
+
+/// Compares a sung `frequency` with the `expected` degree above `root`.
+pub fn sung_answer(frequency: f32, root: Note, expected: Degree, tuning: &Tuning) -> SungAnswer {
+    let cents = cents_above_root(frequency, root, tuning);
+    let (sung, _) = nearest_note(cents, tuning);
+    let off = cents_off(cents, expected, tuning);
+    SungAnswer {
+        frequency,
+        sung,
+        expected,
+        cents: off,
+        correct: off.abs() <= SUNG_TOLERANCE_CENTS,
+    }
+}
+
+/// Places a detected pitch against the drone on `root`.
+pub fn reading(pitch: Pitch, root: Note, tuning: &Tuning) -> IntonationReading {
+    let (note, cents) = nearest_note(cents_above_root(pitch.frequency, root, tuning), tuning);
+    IntonationReading {
+        frequency: pitch.frequency,
+        note,
+        cents,
+        confidence: pitch.confidence,
+    }
+}
+
+/// Bounded queue of readings, dropping the oldest when full.
+///
+/// Allocated up front, so pushing from the input callback never allocates.
+pub struct ReadingQueue {
+    readings: VecDeque<IntonationReading>,
+}
+
+impl Default for ReadingQueue {
+    fn default() -> Self {
+        ReadingQueue {
+            readings: VecDeque::with_capacity(MAX_READINGS),
+        }
+    }
+}
+
+impl ReadingQueue {
+    pub fn push(&mut self, reading: IntonationReading) {
+        if self.readings.len() == MAX_READINGS {
+            self.readings.pop_front();
+        }
+        self.readings.push_back(reading);
+    }
+
+    pub fn drain(&mut self) -> Vec<IntonationReading> {
+        self.readings.drain(..).collect()
+    }
+}
+
+/// Queues a reading against the exercise that is playing, called from the
+/// input callback. Never waits on the lock: a detection that arrives while
+/// the readings are being drained is dropped, the next one is a few ms away.
+pub(crate) fn record(pitch: Pitch) {
+    let Some(exercise) = session::current_exercise() else {
+        return;
+    };
+    let reading = reading(pitch, exercise.root, &tuning::current());
+    if let Ok(mut readings) = READINGS.try_lock() {
+        readings.push(reading);
+    }
+}
+
+/// Ends any previous reading stream, returning the generation of the new one.
+pub(crate) fn open_stream() -> u64 {
+    STREAM_GENERATION.fetch_add(1, Ordering::AcqRel) + 1
+}
+
+pub(crate) fn is_stream_open(generation: u64) -> bool {
+    STREAM_GENERATION.load(Ordering::Acquire) == generation
+}
+
+/// Ends the reading stream, called when listening or playback stops.
+pub(crate) fn close_stream() {
+    STREAM_GENERATION.fetch_add(1, Ordering::AcqRel);
+}
+
+/// Every reading since the last call, oldest first.
+pub fn drain_readings() -> Vec<IntonationReading> {
+    READINGS
+        .lock()
+        .map(|mut readings| readings.drain())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tuning::TuningSystem;
+    use crate::player::exercise_generator::ExerciseGenerator;
+    use test_log::test;
+
+    fn drone(root: Note) -> f32 {
+        Tuning::default().root_frequency(root_note_to_frequency(root))
+    }
+
+    #[test]
+    fn test_any_octave_of_the_degree() {
+        let tuning = Tuning::default();
+        let fifth = drone(Note::One) * 1.5;
+        for octave in [0.5, 1.0, 2.0, 4.0] {
+            let answer = sung_answer(fifth * octave, Note::One, Note::Five.into(), &tuning);
+            assert_eq!(answer.sung, Note::Five);
+            assert!((answer.cents - 1.955).abs() < 0.01, "{:?}", answer);
+            assert!(answer.correct);
+        }
+    }
+
+    #[test]
+    fn test_reports_cents_off_the_expected_degree() {
+        let tuning = Tuning::default();
+        let root = Note::FlatThree;
+        let flat_three = drone(root) * 2f32.powf(330.0 / 1200.0);
+
+        let answer = sung_answer(flat_three, root, Note::FlatThree.into(), &tuning);
+        assert_eq!(answer.sung, Note::FlatThree);
+        assert!((answer.cents - 30.0).abs() < 0.01);
+        assert!(answer.correct);
+
+        let answer = sung_answer(flat_three, root, Note::Three.into(), &tuning);
+        assert!((answer.cents + 70.0).abs() < 0.01);
+        assert!(!answer.correct);
+
+        // A slightly flat root wraps to just below 0, not to 1199 cents
+        let answer = sung_answer(drone(root) * 0.999, root, Note::One.into(), &tuning);
+        assert_eq!(answer.sung, Note::One);
+        assert!((answer.cents + 1.73).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_follows_the_tuning() {
+        let just = Tuning {
+            system: TuningSystem::JustIntonation,
+            ..Tuning::default()
+        };
+        let major_third = drone(Note::One) * 1.25;
+        let answer = sung_answer(major_third, Note::One, Note::Three.into(), &just);
+        assert!(answer.cents.abs() < 0.01);
+
+        let answer = sung_answer(
+            major_third,
+            Note::One,
+            Note::Three.into(),
+            &Tuning::default(),
+        );
+        assert!((answer.cents + 13.69).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_singing_back_the_challenge_over_any_root() {
+        let root = Note::Three;
+        for degree in ALL_NOTES {
+            let challenge = ExerciseGenerator::with_exercise(root, degree, 1)
+                .unwrap()
+                .relative_frequency();
+            let answer = sung_answer(challenge, root, degree.into(), &Tuning::default());
+            assert_eq!(answer.sung, degree);
+            assert!(answer.cents.abs() < 0.01, "{:?}", answer);
+            assert!(answer.correct);
+        }
+    }
+
+    #[test]
+    fn test_reading_against_the_root() {
+        let tuning = Tuning::default();
+        let root = Note::Six;
+        let pitch = Pitch {
+            frequency: drone(root) * 2f32.powf(1090.0 / 1200.0),
+            confidence: 0.95,
+        };
+        let low_seven = reading(pitch, root, &tuning);
+        assert_eq!(low_seven.note, Note::Seven);
+        assert!((low_seven.cents + 10.0).abs() < 0.01);
+        assert_eq!(low_seven.confidence, 0.95);
+
+        // Halfway between 7 and the octave, either one will do
+        let pitch = Pitch {
+            frequency: drone(root) * 2f32.powf(1150.0 / 1200.0),
+            confidence: 0.95,
+        };
+        let halfway = reading(pitch, root, &tuning);
+        assert!([Note::Seven, Note::One].contains(&halfway.note));
+        assert!((halfway.cents.abs() - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_meter_targets_the_degree_being_played() {
+        let root = Note::FlatSix;
+        for degree in ALL_NOTES {
+            let pitch = Pitch {
+                frequency: ExerciseGenerator::with_exercise(root, degree, 1)
+                    .unwrap()
+                    .relative_frequency(),
+                confidence: 0.95,
+            };
+            let on_target = reading(pitch, root, &Tuning::default());
+            assert_eq!(on_target.note, degree);
+            assert!(on_target.cents.abs() < 0.01, "{:?}", on_target);
+        }
+    }
+
+    #[test]
+    fn test_streams_end_when_replaced_or_closed() {
+        // Other tests may stop playback meanwhile, which only ever closes
+        let first = open_stream();
+        let second = open_stream();
+        assert!(!is_stream_open(first));
+
+        close_stream();
+        assert!(!is_stream_open(second));
+    }
+
+    #[test]
+    fn test_reading_queue_keeps_the_newest() {
+        let mut queue = ReadingQueue::default();
+        assert!(queue.readings.capacity() >= MAX_READINGS);
+        for i in 0..MAX_READINGS + 10 {
+            queue.push(IntonationReading {
+                frequency: i as f32,
+                note: Note::One,
+                cents: 0.0,
+                confidence: 1.0,
+            });
+        }
+        let readings = queue.drain();
+        assert_eq!(readings.len(), MAX_READINGS);
+        assert_eq!(readings[0].frequency, 10.0);
+        assert!(queue.drain().is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+
+use crate::player::constants::SAMPLE_RATE;
+
+const CEILING: f32 = 0.98;
+const LOOKAHEAD_SECONDS: f32 = 0.005;
+const RELEASE_SECONDS: f32 = 0.25;
+
+/// Stereo-linked look-ahead peak limiter for the master bus.
+///
+/// The signal is delayed by the look-ahead window so the gain can start
+/// ramping down before a peak reaches the output. The required gain is held
+/// at its minimum over the window and then averaged over the same window,
+/// which guarantees the ramp has reached the required gain by the time the
+/// peak is played, without any step in the gain curve.
+pub struct Limiter {
+    lookahead: usize,
+    release: f32,
+    // Delay line for the dry signal, `lookahead - 1` frames long
+    delay: VecDeque<(f32, f32)>,
+    // Monotonic queue of (frame index, required gain) for the window minimum
+    minimum: VecDeque<(u64, f32)>,
+    // Last `lookahead` held gains, averaged to smooth the attack
+    held: VecDeque<f32>,
+    held_sum: f64,
+    released: f32,
+    frame: u64,
+}
+
+impl Limiter {
+    pub fn new() -> Self {
+        Self::with_sample_rate(SAMPLE_RATE)
+    }
+
+    pub fn with_sample_rate(sample_rate: u32) -> Self {
+        let lookahead = ((LOOKAHEAD_SECONDS * sample_rate as f32) as usize).max(1);
+
+        let mut delay = VecDeque::with_capacity(lookahead);
+        delay.extend(std::iter::repeat_n((0.0, 0.0), lookahead - 1));
+        let mut held = VecDeque::with_capacity(lookahead + 1);
+        held.extend(std::iter::repeat_n(1.0, lookahead));
+
+        Limiter {
+            lookahead,
+            release: 1.0 - (-1.0 / (RELEASE_SECONDS * sample_rate as f32)).exp(),
+            delay,
+            minimum: VecDeque::with_capacity(lookahead + 1),
+            held,
+            held_sum: lookahead as f64,
+            released: 1.0,
+            frame: 0,
+        }
+    }
+
+    /// Latency introduced by the look-ahead, in frames.
+    pub fn latency(&self) -> usize {
+        self.lookahead - 1
+    }
+
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let peak = left.abs().max(right.abs());
+        let required = if peak > CEILING { CEILING / peak } else { 1.0 };
+
+        // Minimum required gain over the look-ahead window
+        while matches!(self.minimum.back(), Some(&(_, gain)) if gain >= required) {
+            self.minimum.pop_back();
+        }
+        self.minimum.push_back((self.frame, required));
+        while matches!(self.minimum.front(), Some(&(index, _)) if index + (self.lookahead as u64) <= self.frame)
+        {
+            self.minimum.pop_front();
+        }
+        let hold = self.minimum.front().map_or(1.0, |&(_, gain)| gain);
+        self.frame += 1;
+
+        // Attack follows the hold immediately, release recovers slowly
+        self.released = if hold < self.released {
+            hold
+        } else {
+            self.released + (hold - self.released) * self.release
+        };
+
+        self.held.push_back(self.released);
+        self.held_sum += self.released as f64;
+        if let Some(oldest) = self.held.pop_front() {
+            self.held_sum -= oldest as f64;
+        }
+        let gain = (self.held_sum / self.lookahead as f64) as f32;
+
+        self.delay.push_back((left, right));
+        let (left, right) = self.delay.pop_front().unwrap_or((left, right));
+
+        // Guard against rounding in the running sum
+        let gain = gain.min(CEILING / left.abs().max(right.abs()).max(CEILING));
+        (left * gain, right * gain)
+    }
+}
+
+impl Default for Limiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+    use test_log::test;
+
+    const FREQUENCY: f32 = 440.0;
+
+    fn sine(amplitude: f32, n: usize) -> f32 {
+        (2.0 * PI * FREQUENCY * n as f32 / SAMPLE_RATE as f32).sin() * amplitude
+    }
+
+    fn run(limiter: &mut Limiter, input: &[f32]) -> Vec<f32> {
+        let mut output: Vec<f32> = input
+            .iter()
+            .map(|&sample| limiter.process(sample, sample).0)
+            .collect();
+        // Flush the look-ahead so the tail is checked too
+        for _ in 0..limiter.latency() {
+            output.push(limiter.process(0.0, 0.0).0);
+        }
+        output
+    }
+
+    // Largest step a full scale sine at FREQUENCY takes between two samples
+    fn max_sine_step() -> f32 {
+        2.0 * PI * FREQUENCY / SAMPLE_RATE as f32
+    }
+
+    fn max_step(samples: &[f32]) -> f32 {
+        samples
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_limiter_passes_quiet_signal_unchanged() {
+        let mut limiter = Limiter::new();
+        let input: Vec<f32> = (0..4800).map(|n| sine(0.5, n)).collect();
+
+        let output = run(&mut limiter, &input);
+
+        for (n, &sample) in input.iter().enumerate() {
+            assert!((output[n + limiter.latency()] - sample).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_limiter_keeps_overdriven_sine_below_full_scale() {
+        let mut limiter = Limiter::new();
+        let input: Vec<f32> = (0..48000).map(|n| sine(8.0, n)).collect();
+
+        let output = run(&mut limiter, &input);
+
+        assert!(output.iter().all(|s| s.abs() <= 1.0));
+        assert!(output.iter().all(|s| s.abs() <= CEILING + 1e-6));
+    }
+
+    #[test]
+    fn test_limiter_catches_single_sample_spike() {
+        let mut limiter = Limiter::new();
+        let mut input: Vec<f32> = (0..4800).map(|n| sine(0.3, n)).collect();
+        input[2000] = 20.0;
+        input[2001] = -20.0;
+
+        let output = run(&mut limiter, &input);
+
+        assert!(output.iter().all(|s| s.abs() <= CEILING + 1e-6));
+    }
+
+    #[test]
+    fn test_limiter_sudden_overdrive_has_no_discontinuity() {
+        let mut limiter = Limiter::new();
+        let input: Vec<f32> = (0..9600)
+            .map(|n| if n < 4800 { sine(0.2, n) } else { sine(4.0, n) })
+            .collect();
+
+        let output = run(&mut limiter, &input);
+
+        assert!(output.iter().all(|s| s.abs() <= CEILING + 1e-6));
+        // The gain ramps over the look-ahead window, so the output never moves
+        // much faster than a full scale sine would
+        assert!(
+            max_step(&output) < max_sine_step() * 1.5,
+            "max step {} exceeds {}",
+            max_step(&output),
+            max_sine_step() * 1.5
+        );
+    }
+
+    #[test]
+    fn test_limiter_recovers_after_peak() {
+        let mut limiter = Limiter::new();
+        let mut input: Vec<f32> = (0..4800).map(|n| sine(4.0, n)).collect();
+        input.extend((4800..96000).map(|n| sine(0.5, n)));
+
+        let output = run(&mut limiter, &input);
+
+        let tail = &output[output.len() - 4800..];
+        let peak = tail.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        assert!(peak > 0.49, "gain did not recover, peak {}", peak);
+    }
+}
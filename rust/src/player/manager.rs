@@ -1,8 +1,11 @@
 use std::collections::HashSet;
-use std::sync::{mpsc, Arc, Mutex};
+use std::sync::{Arc, Mutex};
 
+use crate::api::degrees::Degree;
 use crate::api::notes::Note;
-use crate::player::Player;
+use crate::player::sink::{AudioSink, CpalSink};
+use crate::player::{intonation, session};
+use crate::player::{PlayOptions, Player};
 
 use lazy_static::lazy_static;
 
@@ -11,33 +14,64 @@ lazy_static! {
 }
 
 struct Manager {
-    sender: Option<mpsc::Sender<()>>,
+    sink: Box<dyn AudioSink>,
 }
 
 impl Manager {
     fn new() -> Arc<Mutex<Self>> {
-        Arc::new(Mutex::new(Manager { sender: None }))
+        Arc::new(Mutex::new(Manager {
+            sink: Box::new(CpalSink::new()),
+        }))
     }
 
-    pub fn start_playing(&mut self, notes: HashSet<Note>) {
-        let mut player = Player {};
-        self.sender = Some(player.start(notes, 8));
+    pub fn start_playing(&mut self, player: Player) {
+        self.sink.start(player);
     }
 
     pub fn stop_playing(&mut self) {
-        let _ = match &self.sender {
-            Some(c) => c.send(()),
-            None => Ok(()),
-        };
+        self.sink.stop();
+        session::clear();
+        intonation::close_stream();
+    }
+
+    pub fn set_audio_sink(&mut self, sink: Box<dyn AudioSink>) {
+        self.sink.stop();
+        session::clear();
+        self.sink = sink;
     }
 }
 
 pub fn start_playing(notes: HashSet<Note>) {
+    start_playing_with(PlayOptions::new(notes));
+}
+
+pub fn start_playing_degrees(degrees: HashSet<Degree>) {
+    let mut options = PlayOptions::new(HashSet::new());
+    options.degrees = Some(degrees);
+    start_playing_with(options);
+}
+
+pub fn start_playing_with(options: PlayOptions) {
+    // Preparing the voices for their style takes a while on long samples,
+    // which shouldn't hold up other calls waiting on the manager
+    let player = match Player::with_options(options) {
+        Ok(player) => player,
+        Err(e) => {
+            eprintln!("Error starting player: {}", e);
+            return;
+        }
+    };
     let mut manager = PLAYER_MANAGER.lock().unwrap();
-    manager.start_playing(notes);
+    manager.start_playing(player);
 }
 
 pub fn stop_playing() {
     let mut manager = PLAYER_MANAGER.lock().unwrap();
     manager.stop_playing();
 }
+
+/// Replaces the output used by the next `start_playing`, stopping the current one.
+pub fn set_audio_sink(sink: Box<dyn AudioSink>) {
+    let mut manager = PLAYER_MANAGER.lock().unwrap();
+    manager.set_audio_sink(sink);
+}
@@ -0,0 +1,488 @@
+use std::f32::consts::{FRAC_PI_4, SQRT_2};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::api::mixer::Layer;
+use crate::player::atomic::AtomicF32;
+use crate::player::compressor::Compressor;
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::limiter::Limiter;
+use crate::player::reverb::Reverb;
+
+// Headroom for the tone layers, they are summed before the voice is added
+const TONE_BUS_LEVEL: f32 = 0.5;
+const MAX_GAIN: f32 = 4.0;
+const GAIN_SMOOTHING_SECONDS: f32 = 0.01;
+
+static SETTINGS: MixerSettings = MixerSettings::new();
+
+pub fn settings() -> &'static MixerSettings {
+    &SETTINGS
+}
+
+pub struct LayerSettings {
+    gain: AtomicF32,
+    muted: AtomicBool,
+    pan: AtomicF32,
+}
+
+impl LayerSettings {
+    const fn new() -> Self {
+        LayerSettings {
+            gain: AtomicF32::new(1.0),
+            muted: AtomicBool::new(false),
+            pan: AtomicF32::new(0.0),
+        }
+    }
+
+    pub fn gain(&self) -> f32 {
+        self.gain.load()
+    }
+
+    pub fn set_gain(&self, gain: f32) {
+        self.gain.store(clamp_gain(gain));
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
+
+    /// Stereo position, -1.0 is hard left and 1.0 is hard right.
+    pub fn pan(&self) -> f32 {
+        self.pan.load()
+    }
+
+    pub fn set_pan(&self, pan: f32) {
+        self.pan.store(if pan.is_nan() {
+            0.0
+        } else {
+            pan.clamp(-1.0, 1.0)
+        });
+    }
+
+    fn effective_gain(&self) -> f32 {
+        if self.is_muted() {
+            0.0
+        } else {
+            self.gain()
+        }
+    }
+}
+
+/// Gains shared between the API and the audio thread, read without locking.
+pub struct MixerSettings {
+    drone: LayerSettings,
+    challenge: LayerSettings,
+    answer: LayerSettings,
+    voice: LayerSettings,
+    master: AtomicF32,
+    compressor: AtomicBool,
+    dichotic: AtomicBool,
+}
+
+impl MixerSettings {
+    const fn new() -> Self {
+        MixerSettings {
+            drone: LayerSettings::new(),
+            challenge: LayerSettings::new(),
+            answer: LayerSettings::new(),
+            voice: LayerSettings::new(),
+            master: AtomicF32::new(1.0),
+            compressor: AtomicBool::new(false),
+            dichotic: AtomicBool::new(false),
+        }
+    }
+
+    pub fn layer(&self, layer: Layer) -> &LayerSettings {
+        match layer {
+            Layer::Drone => &self.drone,
+            Layer::Challenge => &self.challenge,
+            Layer::Answer => &self.answer,
+            Layer::Voice => &self.voice,
+        }
+    }
+
+    pub fn master_gain(&self) -> f32 {
+        self.master.load()
+    }
+
+    pub fn set_master_gain(&self, gain: f32) {
+        self.master.store(clamp_gain(gain));
+    }
+
+    pub fn is_compressor_enabled(&self) -> bool {
+        self.compressor.load(Ordering::Relaxed)
+    }
+
+    pub fn set_compressor_enabled(&self, enabled: bool) {
+        self.compressor.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_dichotic(&self) -> bool {
+        self.dichotic.load(Ordering::Relaxed)
+    }
+
+    /// Puts the drone in the left ear and the challenge and answer tones in
+    /// the right ear, overriding their pan settings.
+    pub fn set_dichotic(&self, enabled: bool) {
+        self.dichotic.store(enabled, Ordering::Relaxed);
+    }
+
+    fn effective_pan(&self, layer: Layer) -> f32 {
+        if self.is_dichotic() {
+            match layer {
+                Layer::Drone => return -1.0,
+                Layer::Challenge | Layer::Answer => return 1.0,
+                Layer::Voice => {}
+            }
+        }
+        self.layer(layer).pan()
+    }
+
+    fn compressor_amount(&self) -> f32 {
+        if self.is_compressor_enabled() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+fn clamp_gain(gain: f32) -> f32 {
+    if gain.is_nan() {
+        0.0
+    } else {
+        gain.clamp(0.0, MAX_GAIN)
+    }
+}
+
+/// One frame of every layer, before gain is applied.
+#[derive(Default)]
+pub struct LayerFrame {
+    pub drone: f32,
+    pub challenge: f32,
+    pub answer: f32,
+    pub voice: f32,
+}
+
+// Ramps towards the target value so live changes don't click
+struct SmoothedGain {
+    current: f32,
+}
+
+impl SmoothedGain {
+    fn new(initial: f32) -> Self {
+        SmoothedGain { current: initial }
+    }
+
+    fn next(&mut self, target: f32, coefficient: f32) -> f32 {
+        self.current += (target - self.current) * coefficient;
+        self.current
+    }
+}
+
+// Equal-power pan law, scaled so a centred layer keeps its mono level
+fn pan_gains(pan: f32) -> (f32, f32) {
+    let angle = (pan + 1.0) * FRAC_PI_4;
+    (SQRT_2 * angle.cos(), SQRT_2 * angle.sin())
+}
+
+struct Channel {
+    gain: SmoothedGain,
+    pan: SmoothedGain,
+}
+
+impl Channel {
+    fn new(settings: &MixerSettings, layer: Layer) -> Self {
+        Channel {
+            gain: SmoothedGain::new(settings.layer(layer).effective_gain()),
+            pan: SmoothedGain::new(settings.effective_pan(layer)),
+        }
+    }
+
+    fn process(
+        &mut self,
+        sample: f32,
+        settings: &MixerSettings,
+        layer: Layer,
+        coefficient: f32,
+    ) -> (f32, f32) {
+        let gain = self
+            .gain
+            .next(settings.layer(layer).effective_gain(), coefficient);
+        let (left, right) = pan_gains(self.pan.next(settings.effective_pan(layer), coefficient));
+        (sample * gain * left, sample * gain * right)
+    }
+}
+
+pub struct Mixer {
+    drone: Channel,
+    challenge: Channel,
+    answer: Channel,
+    voice: Channel,
+    master: SmoothedGain,
+    compressor_amount: SmoothedGain,
+    smoothing: f32,
+    reverb: Reverb,
+    compressor: Compressor,
+    limiter: Limiter,
+}
+
+impl Mixer {
+    pub fn new() -> Self {
+        Self::with_settings(settings())
+    }
+
+    fn with_settings(settings: &MixerSettings) -> Self {
+        Mixer {
+            drone: Channel::new(settings, Layer::Drone),
+            challenge: Channel::new(settings, Layer::Challenge),
+            answer: Channel::new(settings, Layer::Answer),
+            voice: Channel::new(settings, Layer::Voice),
+            master: SmoothedGain::new(settings.master_gain()),
+            compressor_amount: SmoothedGain::new(settings.compressor_amount()),
+            smoothing: 1.0 - (-1.0 / (GAIN_SMOOTHING_SECONDS * SAMPLE_RATE as f32)).exp(),
+            reverb: Reverb::new(),
+            compressor: Compressor::new(),
+            limiter: Limiter::new(),
+        }
+    }
+
+    pub fn process(&mut self, layers: &LayerFrame) -> (f32, f32) {
+        self.process_with(layers, settings())
+    }
+
+    fn process_with(&mut self, layers: &LayerFrame, settings: &MixerSettings) -> (f32, f32) {
+        let k = self.smoothing;
+
+        let drone = self.drone.process(layers.drone, settings, Layer::Drone, k);
+        let challenge = self
+            .challenge
+            .process(layers.challenge, settings, Layer::Challenge, k);
+        let answer = self
+            .answer
+            .process(layers.answer, settings, Layer::Answer, k);
+        let voice = self.voice.process(layers.voice, settings, Layer::Voice, k);
+
+        let master = self.master.next(settings.master_gain(), k);
+        let left = ((drone.0 + challenge.0 + answer.0) * TONE_BUS_LEVEL + voice.0) * master;
+        let right = ((drone.1 + challenge.1 + answer.1) * TONE_BUS_LEVEL + voice.1) * master;
+
+        let (left, right) = self.reverb.process(left, right);
+
+        // The compressor always runs so toggling it crossfades instead of jumping
+        let (compressed_left, compressed_right) = self.compressor.process(left, right);
+        let amount = self.compressor_amount.next(settings.compressor_amount(), k);
+        let left = left + (compressed_left - left) * amount;
+        let right = right + (compressed_right - right) * amount;
+
+        self.limiter.process(left, right)
+    }
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn run(
+        mixer: &mut Mixer,
+        settings: &MixerSettings,
+        layers: &LayerFrame,
+        frames: usize,
+    ) -> Vec<f32> {
+        (0..frames)
+            .map(|_| mixer.process_with(layers, settings).0)
+            .collect()
+    }
+
+    fn max_step(samples: &[f32]) -> f32 {
+        samples
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .fold(0.0, f32::max)
+    }
+
+    fn assert_gains(pan: f32, expected: (f32, f32)) {
+        let (left, right) = pan_gains(pan);
+        assert!(
+            (left - expected.0).abs() < 1e-6 && (right - expected.1).abs() < 1e-6,
+            "pan {} gives ({}, {})",
+            pan,
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn test_pan_law() {
+        assert_gains(0.0, (1.0, 1.0));
+        assert_gains(-1.0, (SQRT_2, 0.0));
+        assert_gains(1.0, (0.0, SQRT_2));
+
+        // Equal power: the same loudness wherever the layer sits
+        for i in -10..=10 {
+            let (left, right) = pan_gains(i as f32 / 10.0);
+            assert!((left * left + right * right - 2.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_dichotic_mode_overrides_the_layer_pans() {
+        let settings = MixerSettings::new();
+        settings.layer(Layer::Drone).set_pan(0.5);
+        settings.layer(Layer::Challenge).set_pan(-0.5);
+        settings.layer(Layer::Answer).set_pan(-1.0);
+        settings.layer(Layer::Voice).set_pan(0.25);
+
+        settings.set_dichotic(true);
+        assert_eq!(settings.effective_pan(Layer::Drone), -1.0);
+        assert_eq!(settings.effective_pan(Layer::Challenge), 1.0);
+        assert_eq!(settings.effective_pan(Layer::Answer), 1.0);
+        assert_eq!(settings.effective_pan(Layer::Voice), 0.25);
+
+        // With only the drone playing, the right ear stays silent
+        let mut mixer = Mixer::with_settings(&settings);
+        let drone = LayerFrame {
+            drone: 0.5,
+            ..LayerFrame::default()
+        };
+        let (left, right) = (0..SAMPLE_RATE / 10)
+            .map(|_| mixer.process_with(&drone, &settings))
+            .last()
+            .unwrap();
+        assert!(left > 0.3);
+        assert!(right.abs() < 1e-6);
+
+        settings.set_dichotic(false);
+        assert_eq!(settings.effective_pan(Layer::Drone), 0.5);
+        assert_eq!(settings.effective_pan(Layer::Challenge), -0.5);
+    }
+
+    // Left output of `layers` once the gains have settled
+    fn settled(mixer: &mut Mixer, settings: &MixerSettings, layers: &LayerFrame) -> f32 {
+        *run(mixer, settings, layers, SAMPLE_RATE as usize / 10)
+            .last()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_layer_gain_scales_the_layer() {
+        let settings = MixerSettings::new();
+        let mut mixer = Mixer::with_settings(&settings);
+        let drone = LayerFrame {
+            drone: 0.2,
+            ..LayerFrame::default()
+        };
+        let unity = settled(&mut mixer, &settings, &drone);
+        assert!((unity - 0.2 * TONE_BUS_LEVEL).abs() < 1e-4, "{}", unity);
+
+        settings.layer(Layer::Drone).set_gain(0.5);
+        let half = settled(&mut mixer, &settings, &drone);
+        assert!((half - unity * 0.5).abs() < 1e-4, "{}", half);
+
+        // Other layers keep their gain
+        let voice = LayerFrame {
+            voice: 0.2,
+            ..LayerFrame::default()
+        };
+        let voice_level = settled(&mut mixer, &settings, &voice);
+        assert!((voice_level - 0.2).abs() < 1e-4, "{}", voice_level);
+    }
+
+    #[test]
+    fn test_muted_layer_is_silent() {
+        let settings = MixerSettings::new();
+        settings.layer(Layer::Challenge).set_muted(true);
+        let mut mixer = Mixer::with_settings(&settings);
+
+        let challenge = LayerFrame {
+            challenge: 0.2,
+            ..LayerFrame::default()
+        };
+        assert!(settled(&mut mixer, &settings, &challenge).abs() < 1e-6);
+
+        let both = LayerFrame {
+            challenge: 0.2,
+            answer: 0.2,
+            ..LayerFrame::default()
+        };
+        let answer_only = settled(&mut mixer, &settings, &both);
+        assert!((answer_only - 0.2 * TONE_BUS_LEVEL).abs() < 1e-4);
+
+        settings.layer(Layer::Challenge).set_muted(false);
+        let unmuted = settled(&mut mixer, &settings, &both);
+        assert!((unmuted - 0.4 * TONE_BUS_LEVEL).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_master_gain_scales_the_mix() {
+        let settings = MixerSettings::new();
+        let mut mixer = Mixer::with_settings(&settings);
+        let layers = LayerFrame {
+            drone: 0.2,
+            voice: 0.1,
+            ..LayerFrame::default()
+        };
+        let unity = settled(&mut mixer, &settings, &layers);
+
+        settings.set_master_gain(0.25);
+        let quarter = settled(&mut mixer, &settings, &layers);
+        assert!((quarter - unity * 0.25).abs() < 1e-4, "{}", quarter);
+    }
+
+    #[test]
+    fn test_gain_changes_ramp() {
+        let settings = MixerSettings::new();
+        let mut mixer = Mixer::with_settings(&settings);
+        let voice = LayerFrame {
+            voice: 0.5,
+            ..LayerFrame::default()
+        };
+        let before = settled(&mut mixer, &settings, &voice);
+
+        // A step would drop by 0.5 in one frame
+        settings.layer(Layer::Voice).set_gain(0.0);
+        let ramp = run(&mut mixer, &settings, &voice, SAMPLE_RATE as usize / 10);
+        assert!(max_step(&[&[before], &ramp[..]].concat()) < 1e-2);
+        assert!(ramp[0] > 0.45, "first frame {}", ramp[0]);
+        assert!(ramp.last().unwrap().abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_compressor_crossfades_on_toggle() {
+        let settings = MixerSettings::new();
+        let mut mixer = Mixer::with_settings(&settings);
+        // A centred drone at full scale reaches the bus at -6 dBFS, 3 dB of
+        // reduction above the knee
+        let loud = LayerFrame {
+            drone: 1.0,
+            ..LayerFrame::default()
+        };
+        let tenth = SAMPLE_RATE as usize / 10;
+
+        let dry = run(&mut mixer, &settings, &loud, 5 * tenth);
+        let dry_level = *dry.last().unwrap();
+        assert!((dry_level - 0.5).abs() < 1e-3, "dry level {}", dry_level);
+
+        settings.set_compressor_enabled(true);
+        let on = run(&mut mixer, &settings, &loud, 2 * tenth);
+        let compressed_level = 0.5 * 10.0_f32.powf(-3.0 / 20.0);
+        assert!((on.last().unwrap() - compressed_level).abs() < 1e-3);
+        assert!(max_step(&[&[dry_level], &on[..]].concat()) < 1e-3);
+
+        settings.set_compressor_enabled(false);
+        let off = run(&mut mixer, &settings, &loud, 2 * tenth);
+        assert!((off.last().unwrap() - dry_level).abs() < 1e-3);
+        assert!(max_step(&[&on[on.len() - 1..], &off[..]].concat()) < 1e-3);
+    }
+}
@@ -1,78 +1,128 @@
+mod atomic;
+pub mod compressor;
 pub mod constants;
 pub mod exercise_generator;
+pub mod input;
+pub mod intonation;
+pub mod limiter;
 pub mod manager;
+pub mod mixer;
+pub mod pitch;
+pub mod playback;
+pub mod quiz;
+pub mod resample;
+pub mod reverb;
+pub mod session;
+pub mod sink;
+pub mod speech;
+pub mod stretch;
+#[cfg(test)]
+mod test_signals;
+pub mod tuning;
 pub mod wav;
 
-use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::collections::HashSet;
 use std::f32::consts::PI;
-use std::sync::mpsc;
-use std::thread;
 use std::time::Duration;
 
+use crate::api::degrees::Degree;
 use crate::api::notes::Note;
 use crate::player::constants::{
-    FADE_IN_DURATION, FADE_OUT_DURATION, RELATIVE_ANSWER_FADE_IN_START_TIME,
-    RELATIVE_ANSWER_FADE_OUT_START_TIME, RELATIVE_CHALLENGE_FADE_IN_START_TIME,
-    RELATIVE_CHALLENGE_FADE_OUT_START_TIME, ROOT_END_TIME,
+    DEFAULT_REPETITIONS, DRONE_AMPLITUDE, FADE_IN_DURATION, FADE_OUT_DURATION, RELATIVE_AMPLITUDE,
+    RELATIVE_ANSWER_FADE_IN_START_TIME, RELATIVE_ANSWER_FADE_OUT_START_TIME,
+    RELATIVE_CHALLENGE_FADE_IN_START_TIME, RELATIVE_CHALLENGE_FADE_OUT_START_TIME, ROOT_END_TIME,
+    SAMPLE_RATE,
 };
-use crate::player::exercise_generator::{ExerciseGenerator, VolumeInfo};
-
-pub struct Player {}
+use crate::player::exercise_generator::{ExerciseGenerator, Timing, VolumeInfo};
+use crate::player::mixer::{LayerFrame, Mixer};
+
+/// What to practice and how, used to start a session.
+pub struct PlayOptions {
+    pub notes: HashSet<Note>,
+    /// Replaces `notes` with any cent based degrees when set.
+    pub degrees: Option<HashSet<Degree>>,
+    pub repetitions: u8,
+    pub seed: Option<u64>,
+    pub timing: Timing,
+}
 
-impl Player {
-    pub fn start(&mut self, notes: HashSet<Note>, repetitions: u8) -> mpsc::Sender<()> {
-        let (tx, rx) = mpsc::channel();
-
-        thread::spawn(move || {
-            let host = cpal::default_host();
-            let device = host
-                .default_output_device()
-                .expect("No output device available");
-            let config = cpal::StreamConfig {
-                channels: 2,
-                sample_rate: cpal::SampleRate(48000),
-                buffer_size: cpal::BufferSize::Default,
-            };
+impl PlayOptions {
+    pub fn new(notes: HashSet<Note>) -> Self {
+        PlayOptions {
+            notes,
+            degrees: None,
+            repetitions: DEFAULT_REPETITIONS,
+            seed: None,
+            timing: Timing::Normal,
+        }
+    }
+}
 
-            let mut exercise_generator = ExerciseGenerator::new(notes, repetitions).unwrap();
+/// Renders a practice session into interleaved stereo buffers.
+///
+/// The player doesn't own an output device, an `AudioSink` pulls buffers from
+/// it through `render`.
+pub struct Player {
+    exercise_generator: ExerciseGenerator,
+    mixer: Mixer,
+}
 
-            let stream = device
-                .build_output_stream(
-                    &config,
-                    move |data: &mut [f32], _| {
-                        Self::write_data_timed(data, &mut exercise_generator)
-                    },
-                    err_fn,
-                )
-                .unwrap();
+impl Player {
+    pub fn new(notes: HashSet<Note>, repetitions: u8) -> Result<Self, &'static str> {
+        Ok(Self::from_generator(ExerciseGenerator::new(
+            notes,
+            repetitions,
+        )?))
+    }
 
-            stream.play().unwrap();
-            let _ = rx.recv().is_ok();
-        });
+    pub fn with_options(options: PlayOptions) -> Result<Self, &'static str> {
+        let mut exercise_generator = match (options.degrees, options.seed) {
+            (Some(degrees), seed) => {
+                ExerciseGenerator::with_degrees(degrees, options.repetitions, seed)?
+            }
+            (None, Some(seed)) => {
+                ExerciseGenerator::with_seed(options.notes, options.repetitions, seed)?
+            }
+            (None, None) => ExerciseGenerator::new(options.notes, options.repetitions)?,
+        };
+        exercise_generator.set_timing(options.timing);
+        Ok(Self::from_generator(exercise_generator))
+    }
 
-        tx
+    pub fn from_generator(exercise_generator: ExerciseGenerator) -> Self {
+        Player {
+            exercise_generator,
+            mixer: Mixer::new(),
+        }
     }
 
-    fn write_data_timed(data: &mut [f32], exercise_generator: &mut ExerciseGenerator) {
-        // setup generator
-        exercise_generator.generate();
+    pub fn render(&mut self, data: &mut [f32]) {
+        Self::write_data_timed(data, &mut self.exercise_generator, &mut self.mixer)
+    }
 
-        let amplitude1 = 0.8; // Base volume for the first tone
-        let amplitude2 = 0.3; // Base volume for the second tone
+    fn write_data_timed(
+        data: &mut [f32],
+        exercise_generator: &mut ExerciseGenerator,
+        mixer: &mut Mixer,
+    ) {
+        let amplitude1 = DRONE_AMPLITUDE; // Base volume for the first tone
+        let amplitude2 = RELATIVE_AMPLITUDE; // Base volume for the second tone
         let mut iter = data.chunks_exact_mut(2); // Stereo (left, right)
 
-        let frequency1 = exercise_generator.root_frequency();
-        let frequency2 = exercise_generator.relative_frequency();
-
-        let elapsed = exercise_generator.time.elapsed(); // Get the elapsed time since the stream started
-        let sample_rate = cpal::SampleRate(48000).0 as f32;
+        let sample_rate = SAMPLE_RATE as f32;
 
         // Define fade-in and fade-out durations
         let fade_in_duration = Duration::from_secs(FADE_IN_DURATION); // First tone fade-in duration
         let fade_out_duration = Duration::from_secs(FADE_OUT_DURATION); // First tone fade-out duration
 
         for frame in iter.by_ref() {
+            // Move on to the next exercise exactly on its frame
+            exercise_generator.generate();
+
+            let frequency1 = exercise_generator.root_frequency();
+            let frequency2 = exercise_generator.relative_frequency();
+            let elapsed = exercise_generator.elapsed(); // Time since the exercise started
+
             let command = exercise_generator.generate_command();
             let fade_in_factor1 = match command.play_root {
                 VolumeInfo::FadeIn => elapsed.as_secs_f32() / fade_in_duration.as_secs_f32(),
@@ -105,7 +155,10 @@ impl Player {
 
                 base_value * fade_in_factor1 // Apply fade-in factor to the first tone
             };
-            let mut value2: f32 = 0.0;
+            let mut layers = LayerFrame {
+                drone: value1,
+                ..LayerFrame::default()
+            };
 
             if command.play_challenge != VolumeInfo::Silent {
                 let fade_in_factor2 = match command.play_challenge {
@@ -122,10 +175,10 @@ impl Player {
                     VolumeInfo::Silent => 0.0,
                 };
 
-                value2 = (2.0 * PI * frequency2 * exercise_generator.sample_clock / sample_rate)
-                    .sin()
-                    * amplitude2
-                    * fade_in_factor2;
+                layers.challenge =
+                    (2.0 * PI * frequency2 * exercise_generator.sample_clock / sample_rate).sin()
+                        * amplitude2
+                        * fade_in_factor2;
             } else if command.play_answer != VolumeInfo::Silent {
                 let fade_in_factor2 = match command.play_answer {
                     VolumeInfo::FadeIn => {
@@ -140,41 +193,26 @@ impl Player {
                     VolumeInfo::Silent => 0.0,
                 };
 
-                value2 = (2.0 * PI * frequency2 * exercise_generator.sample_clock / sample_rate)
-                    .sin()
-                    * amplitude2
-                    * fade_in_factor2;
+                layers.answer =
+                    (2.0 * PI * frequency2 * exercise_generator.sample_clock / sample_rate).sin()
+                        * amplitude2
+                        * fade_in_factor2;
             }
 
-            // Combine the two signals
-            let mut combined_left = (value1 + value2) * 0.5;
-            let mut combined_right = (value1 + value2) * 0.5;
-
             // Add WAV playback after 10 seconds
             if command.play_voice_answer {
                 if let Some(wav_sample) = exercise_generator.get_next_voice_sample() {
-                    combined_left += wav_sample;
-                    combined_right += wav_sample;
+                    layers.voice = wav_sample;
                 }
             }
 
-            // Normalize to prevent clipping (keep values within [-1.0, 1.0])
-            let max_value = combined_left.abs().max(combined_right.abs());
-            let normalization_factor = if max_value > 1.0 {
-                1.0 / max_value
-            } else {
-                1.0
-            };
+            // Apply layer gains and limit the master bus
+            let (left, right) = mixer.process(&layers);
 
-            // Apply the normalization factor to avoid clipping
-            frame[0] = cpal::Sample::from(&(combined_left * normalization_factor)); // Left channel
-            frame[1] = cpal::Sample::from(&(combined_right * normalization_factor)); // Right channel
+            frame[0] = cpal::Sample::from(&left); // Left channel
+            frame[1] = cpal::Sample::from(&right); // Right channel
 
             exercise_generator.increment_sample_clock();
         }
     }
 }
-
-fn err_fn(err: cpal::StreamError) {
-    eprintln!("Error: {:?}", err);
-}
@@ -0,0 +1,236 @@
+// Range of a singing voice, with room for a low male voice and a whistle
+const MIN_FREQUENCY: u32 = 60;
+const MAX_FREQUENCY: u32 = 1500;
+// Below this the cumulative mean normalized difference counts as a period
+const YIN_THRESHOLD: f32 = 0.15;
+// Quieter frames are treated as silence rather than guessed at
+const MIN_RMS: f32 = 0.01;
+
+/// A detected fundamental frequency.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pitch {
+    pub frequency: f32,
+    /// How periodic the frame is, from 0 to 1.
+    pub confidence: f32,
+}
+
+/// YIN pitch detector for mono frames at a fixed sample rate.
+///
+/// Works on frames of `frame_len` samples, enough to hold two periods of
+/// `MIN_FREQUENCY`. The scratch buffer is allocated once, so detection can
+/// run on an input callback.
+pub struct PitchDetector {
+    sample_rate: u32,
+    difference: Vec<f32>,
+}
+
+impl PitchDetector {
+    pub fn new(sample_rate: u32) -> Self {
+        PitchDetector {
+            sample_rate,
+            difference: vec![0.0; Self::max_lag(sample_rate) + 2],
+        }
+    }
+
+    /// The number of samples `detect` looks at.
+    pub fn frame_len(&self) -> usize {
+        // Half the frame is compared against itself up to one past `max_lag`
+        (2 * Self::max_lag(self.sample_rate) + 2).next_power_of_two()
+    }
+
+    fn max_lag(sample_rate: u32) -> usize {
+        (sample_rate / MIN_FREQUENCY) as usize
+    }
+
+    /// The pitch of the first `frame_len` samples, `None` when the frame is
+    /// silent, unpitched or too short.
+    pub fn detect(&mut self, frame: &[f32]) -> Option<Pitch> {
+        let frame = frame.get(..self.frame_len())?;
+        let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+        if rms < MIN_RMS {
+            return None;
+        }
+
+        let window = frame.len() / 2;
+        let max_lag = Self::max_lag(self.sample_rate) + 1;
+        let min_lag = (self.sample_rate / MAX_FREQUENCY) as usize;
+
+        // Cumulative mean normalized difference
+        self.difference[0] = 1.0;
+        let mut running_sum = 0.0;
+        for lag in 1..=max_lag {
+            let difference: f32 = (0..window)
+                .map(|i| {
+                    let delta = frame[i] - frame[i + lag];
+                    delta * delta
+                })
+                .sum();
+            running_sum += difference;
+            self.difference[lag] = if running_sum > 0.0 {
+                difference * lag as f32 / running_sum
+            } else {
+                1.0
+            };
+        }
+
+        let mut lag =
+            (min_lag.max(2)..max_lag).find(|&lag| self.difference[lag] < YIN_THRESHOLD)?;
+        while lag + 1 < max_lag && self.difference[lag + 1] < self.difference[lag] {
+            lag += 1;
+        }
+
+        Some(Pitch {
+            frequency: self.sample_rate as f32 / self.refine(lag),
+            confidence: (1.0 - self.difference[lag]).clamp(0.0, 1.0),
+        })
+    }
+
+    /// Parabolic interpolation around the minimum at `lag`.
+    fn refine(&self, lag: usize) -> f32 {
+        let (before, at, after) = (
+            self.difference[lag - 1],
+            self.difference[lag],
+            self.difference[lag + 1],
+        );
+        let curvature = before - 2.0 * at + after;
+        if curvature.abs() < f32::EPSILON {
+            return lag as f32;
+        }
+        lag as f32 + 0.5 * (before - after) / curvature
+    }
+}
+
+/// Runs a `PitchDetector` over a stream of samples, one detection every
+/// quarter frame.
+pub struct PitchTracker {
+    detector: PitchDetector,
+    buffer: Vec<f32>,
+}
+
+impl PitchTracker {
+    pub fn new(sample_rate: u32) -> Self {
+        let detector = PitchDetector::new(sample_rate);
+        let buffer = Vec::with_capacity(2 * detector.frame_len());
+        PitchTracker { detector, buffer }
+    }
+
+    fn hop(&self) -> usize {
+        self.detector.frame_len() / 4
+    }
+
+    /// Adds samples and calls `on_pitch` for every frame they complete.
+    pub fn push(&mut self, samples: &[f32], mut on_pitch: impl FnMut(Option<Pitch>)) {
+        let frame_len = self.detector.frame_len();
+        let hop = self.hop();
+        for chunk in samples.chunks(hop) {
+            self.buffer.extend_from_slice(chunk);
+            while self.buffer.len() >= frame_len {
+                on_pitch(self.detector.detect(&self.buffer));
+                self.buffer.drain(..hop);
+            }
+        }
+    }
+}
+
+/// Every detection over `samples`, as a `PitchTracker` would report them.
+pub fn detect_pitches(samples: &[f32], sample_rate: u32) -> Vec<Option<Pitch>> {
+    let mut pitches = Vec::new();
+    PitchTracker::new(sample_rate).push(samples, |pitch| pitches.push(pitch));
+    pitches
+}
+
+/// The median frequency of the voiced detections, steady against the
+/// odd octave error at the start or end of a note.
+pub fn median_pitch(pitches: &[Option<Pitch>]) -> Option<Pitch> {
+    let mut voiced: Vec<Pitch> = pitches.iter().flatten().copied().collect();
+    if voiced.is_empty() {
+        return None;
+    }
+    voiced.sort_by(|a, b| a.frequency.total_cmp(&b.frequency));
+    Some(voiced[voiced.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::constants::SAMPLE_RATE;
+    use std::f32::consts::PI;
+    use test_log::test;
+
+    /// A voice-like tone with a weaker fundamental than its second harmonic.
+    fn tone(frequency: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                let phase = 2.0 * PI * frequency * i as f32 / SAMPLE_RATE as f32;
+                0.2 * phase.sin() + 0.3 * (2.0 * phase).sin() + 0.1 * (3.0 * phase).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_the_fundamental() {
+        let mut detector = PitchDetector::new(SAMPLE_RATE);
+        for frequency in [82.4, 146.8, 220.0, 261.6, 440.0, 987.8] {
+            let pitch = detector
+                .detect(&tone(frequency, detector.frame_len()))
+                .unwrap();
+            let cents = 1200.0 * (pitch.frequency / frequency).log2();
+            assert!(
+                cents.abs() < 5.0,
+                "{} Hz detected as {:?}",
+                frequency,
+                pitch
+            );
+            assert!(pitch.confidence > 0.9);
+        }
+    }
+
+    #[test]
+    fn test_frame_holds_the_longest_lag_at_any_rate() {
+        // Twice the longest lag is a power of two at these rates
+        for sample_rate in [7680, 7739, 15360, 30720, 30779] {
+            let mut detector = PitchDetector::new(sample_rate);
+            let frame: Vec<f32> = (0..detector.frame_len())
+                .map(|i| (2.0 * PI * 220.0 * i as f32 / sample_rate as f32).sin())
+                .collect();
+            let pitch = detector.detect(&frame).unwrap();
+            assert!(
+                (pitch.frequency - 220.0).abs() < 2.0,
+                "{:?} at {} Hz",
+                pitch,
+                sample_rate
+            );
+        }
+    }
+
+    #[test]
+    fn test_silence_and_noise_have_no_pitch() {
+        let mut detector = PitchDetector::new(SAMPLE_RATE);
+        assert_eq!(detector.detect(&vec![0.0; detector.frame_len()]), None);
+        assert_eq!(detector.detect(&[0.5; 10]), None);
+
+        let mut state = 0x2545_f491u32;
+        let noise: Vec<f32> = (0..detector.frame_len())
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as f32 / u32::MAX as f32 - 0.5
+            })
+            .collect();
+        assert_eq!(detector.detect(&noise), None);
+    }
+
+    #[test]
+    fn test_tracker_follows_a_note_change() {
+        let half = SAMPLE_RATE as usize / 2;
+        let mut samples = tone(220.0, half);
+        samples.extend(tone(330.0, half));
+
+        let pitches = detect_pitches(&samples, SAMPLE_RATE);
+        let first = median_pitch(&pitches[..pitches.len() / 3]).unwrap();
+        let last = median_pitch(&pitches[pitches.len() * 2 / 3..]).unwrap();
+        assert!((first.frequency - 220.0).abs() < 1.0);
+        assert!((last.frequency - 330.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use crate::player::atomic::AtomicF32;
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::stretch::time_stretch;
+
+const MIN_VOICE_TEMPO: f32 = 0.5;
+const MAX_VOICE_TEMPO: f32 = 2.0;
+const MAX_VOICE_PITCH: f32 = 12.0;
+
+static VOICE_TEMPO: AtomicF32 = AtomicF32::new(1.0);
+static VOICE_PITCH: AtomicF32 = AtomicF32::new(0.0);
+
+/// How voice answers are played back.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VoiceStyle {
+    /// Speed relative to the recording, 2 answers in half the time.
+    pub tempo: f32,
+    /// Shift in semitones, independent of the tempo.
+    pub pitch: f32,
+}
+
+impl Default for VoiceStyle {
+    fn default() -> Self {
+        VoiceStyle {
+            tempo: 1.0,
+            pitch: 0.0,
+        }
+    }
+}
+
+impl VoiceStyle {
+    pub fn new(tempo: f32, pitch: f32) -> Result<Self, String> {
+        if !(MIN_VOICE_TEMPO..=MAX_VOICE_TEMPO).contains(&tempo) {
+            return Err(format!(
+                "Voice tempo must be between {} and {}, got {}",
+                MIN_VOICE_TEMPO, MAX_VOICE_TEMPO, tempo
+            ));
+        }
+        if !(-MAX_VOICE_PITCH..=MAX_VOICE_PITCH).contains(&pitch) {
+            return Err(format!(
+                "Voice pitch must be between -{0} and {0} semitones, got {1}",
+                MAX_VOICE_PITCH, pitch
+            ));
+        }
+        Ok(VoiceStyle { tempo, pitch })
+    }
+
+    /// The cursor rate that shifts the pitch.
+    pub fn rate(&self) -> f32 {
+        2f32.powf(self.pitch / 12.0)
+    }
+
+    /// Time-stretches `samples` so that playing them at `rate` gives both
+    /// the tempo and the pitch. Allocates, so run it before playback starts.
+    pub fn prepare(&self, samples: &[f32]) -> Vec<f32> {
+        time_stretch(samples, self.rate() / self.tempo)
+    }
+}
+
+pub fn set_voice_style(tempo: f32, pitch: f32) -> Result<(), String> {
+    let style = VoiceStyle::new(tempo, pitch)?;
+    VOICE_TEMPO.store(style.tempo);
+    VOICE_PITCH.store(style.pitch);
+    Ok(())
+}
+
+pub fn voice_style() -> VoiceStyle {
+    VoiceStyle {
+        tempo: VOICE_TEMPO.load(),
+        pitch: VOICE_PITCH.load(),
+    }
+}
+
+/// A position into shared, already decoded samples.
+///
+/// Cursors are cheap to create and never copy the samples, so every exercise
+/// can get its own. Rates other than 1 read between samples with linear
+/// interpolation, which changes pitch and duration together.
+#[derive(Clone)]
+pub struct PlaybackCursor {
+    samples: Arc<[f32]>,
+    position: f64,
+    rate: f32,
+    gain: f32,
+}
+
+impl PlaybackCursor {
+    pub fn new(samples: Arc<[f32]>) -> Self {
+        PlaybackCursor {
+            samples,
+            position: 0.0,
+            rate: 1.0,
+            gain: 1.0,
+        }
+    }
+
+    /// Moves to `frame`, past the end finishes playback.
+    pub fn seek(&mut self, frame: usize) {
+        self.position = frame.min(self.samples.len()) as f64;
+    }
+
+    pub fn seek_seconds(&mut self, seconds: f32) {
+        self.seek((seconds.max(0.0) * SAMPLE_RATE as f32) as usize);
+    }
+
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain.max(0.0);
+    }
+
+    /// Samples read per output sample, 2 plays an octave up in half the time.
+    pub fn set_rate(&mut self, rate: f32) -> Result<(), String> {
+        if !(rate.is_finite() && rate > 0.0) {
+            return Err(format!("Playback rate must be positive, got {}", rate));
+        }
+        self.rate = rate;
+        Ok(())
+    }
+
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Current frame, rounded down.
+    pub fn position(&self) -> usize {
+        self.position as usize
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.samples.len() as f64
+    }
+
+    pub fn next_sample(&mut self) -> Option<f32> {
+        if self.is_finished() {
+            return None;
+        }
+        let index = self.position as usize;
+        let fraction = (self.position - index as f64) as f32;
+        let current = self.samples[index];
+        let sample = match self.samples.get(index + 1) {
+            Some(next) if fraction > 0.0 => current + (next - current) * fraction,
+            _ => current,
+        };
+        self.position += self.rate as f64;
+        Some(sample * self.gain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::test_signals::{frequency, sine};
+    use crate::player::wav::WavFile;
+    use test_log::test;
+
+    fn ramp(len: usize) -> Arc<[f32]> {
+        (0..len).map(|i| i as f32).collect::<Vec<_>>().into()
+    }
+
+    fn drain(cursor: &mut PlaybackCursor) -> Vec<f32> {
+        std::iter::from_fn(|| cursor.next_sample()).collect()
+    }
+
+    #[test]
+    fn test_cursors_share_samples() {
+        let samples = ramp(4);
+        let mut first = PlaybackCursor::new(samples.clone());
+        let mut second = PlaybackCursor::new(samples.clone());
+        assert_eq!(drain(&mut first), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(drain(&mut second), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(Arc::strong_count(&samples), 3);
+    }
+
+    #[test]
+    fn test_seek_and_gain() {
+        let mut cursor = PlaybackCursor::new(ramp(5));
+        cursor.seek(3);
+        cursor.set_gain(0.5);
+        assert_eq!(drain(&mut cursor), vec![1.5, 2.0]);
+        assert!(cursor.is_finished());
+
+        cursor.seek(100);
+        assert_eq!(cursor.position(), 5);
+        assert_eq!(cursor.next_sample(), None);
+    }
+
+    #[test]
+    fn test_rate() {
+        let mut cursor = PlaybackCursor::new(ramp(8));
+        cursor.set_rate(2.0).unwrap();
+        assert_eq!(drain(&mut cursor), vec![0.0, 2.0, 4.0, 6.0]);
+
+        cursor.seek(0);
+        cursor.set_rate(0.5).unwrap();
+        let slow = drain(&mut cursor);
+        assert_eq!(slow.len(), 16);
+        assert_eq!(&slow[..4], &[0.0, 0.5, 1.0, 1.5]);
+
+        assert!(cursor.set_rate(0.0).is_err());
+        assert!(cursor.set_rate(f32::NAN).is_err());
+    }
+
+    fn play(style: VoiceStyle, samples: &[f32]) -> Vec<f32> {
+        let mut cursor = PlaybackCursor::new(style.prepare(samples).into());
+        cursor.set_rate(style.rate()).unwrap();
+        drain(&mut cursor)
+    }
+
+    #[test]
+    fn test_voice_style_tempo_and_pitch() {
+        let samples = sine(200.0, SAMPLE_RATE as usize);
+        let seconds = |played: &[f32]| played.len() as f32 / SAMPLE_RATE as f32;
+
+        let faster = play(
+            VoiceStyle {
+                tempo: 2.0,
+                pitch: 0.0,
+            },
+            &samples,
+        );
+        assert!((seconds(&faster) - 0.5).abs() < 0.01);
+        assert!((frequency(&faster) - 200.0).abs() < 10.0);
+
+        let octave_up = play(
+            VoiceStyle {
+                tempo: 1.0,
+                pitch: 12.0,
+            },
+            &samples,
+        );
+        assert!((seconds(&octave_up) - 1.0).abs() < 0.01);
+        assert!((frequency(&octave_up) - 400.0).abs() < 15.0);
+
+        let slow_and_low = play(
+            VoiceStyle {
+                tempo: 0.5,
+                pitch: -12.0,
+            },
+            &samples,
+        );
+        assert!((seconds(&slow_and_low) - 2.0).abs() < 0.01);
+        assert!((frequency(&slow_and_low) - 100.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_voice_style_on_decoded_samples() {
+        // Half a second of a 440 Hz sine, through the Vorbis decoder
+        let wav = WavFile::new(include_bytes!("../../tests/fixtures/sine.ogg")).unwrap();
+        let seconds = |played: &[f32]| played.len() as f32 / SAMPLE_RATE as f32;
+
+        let faster_and_higher = play(
+            VoiceStyle {
+                tempo: 2.0,
+                pitch: 12.0,
+            },
+            wav.samples(),
+        );
+        assert!((seconds(&faster_and_higher) - 0.25).abs() < 0.01);
+        assert!((frequency(&faster_and_higher) - 880.0).abs() < 30.0);
+
+        let slower = play(
+            VoiceStyle {
+                tempo: 0.5,
+                pitch: 0.0,
+            },
+            wav.samples(),
+        );
+        assert!((seconds(&slower) - 1.0).abs() < 0.01);
+        assert!((frequency(&slower) - 440.0).abs() < 15.0);
+    }
+
+    #[test]
+    fn test_voice_style_checks_range() {
+        assert!(VoiceStyle::new(3.0, 0.0).is_err());
+        assert!(VoiceStyle::new(0.25, 0.0).is_err());
+        assert!(VoiceStyle::new(1.0, 13.0).is_err());
+        assert!(VoiceStyle::new(1.0, -13.0).is_err());
+        assert!(VoiceStyle::new(f32::NAN, 0.0).is_err());
+        assert_eq!(
+            VoiceStyle::new(2.0, -12.0),
+            Ok(VoiceStyle {
+                tempo: 2.0,
+                pitch: -12.0
+            })
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::api::degrees::Degree;
+use crate::api::quiz::{AnswerOutcome, Score};
+use crate::player::session::ExerciseInfo;
+
+/// Checks answers against the exercises of a session and keeps the score.
+///
+/// Each exercise counts once, further answers to the same exercise are
+/// reported as `AlreadyAnswered` and don't change the score. Neither do
+/// answers given after the voice has spoken the answer, reported as
+/// `Revealed`.
+#[derive(Default)]
+pub struct Quiz {
+    score: Score,
+    answered_exercise: Option<u32>,
+}
+
+impl Quiz {
+    pub fn new() -> Self {
+        Quiz::default()
+    }
+
+    pub fn answer(
+        &mut self,
+        exercise: Option<ExerciseInfo>,
+        answer: impl Into<Degree>,
+    ) -> AnswerOutcome {
+        let Some(exercise) = exercise else {
+            return AnswerOutcome::NoExercise;
+        };
+        if self.answered_exercise == Some(exercise.id) {
+            return AnswerOutcome::AlreadyAnswered;
+        }
+        if exercise.revealed {
+            return AnswerOutcome::Revealed;
+        }
+
+        self.answered_exercise = Some(exercise.id);
+        self.score.answered += 1;
+        if check_answer(exercise.relative, answer.into()) {
+            self.score.correct += 1;
+            AnswerOutcome::Correct
+        } else {
+            AnswerOutcome::Incorrect {
+                expected: exercise.relative,
+            }
+        }
+    }
+
+    pub fn score(&self) -> Score {
+        self.score
+    }
+}
+
+pub fn check_answer(expected: Degree, answer: Degree) -> bool {
+    expected == answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::notes::Note;
+    use test_log::test;
+
+    fn exercise(id: u32, relative: Note) -> Option<ExerciseInfo> {
+        Some(ExerciseInfo {
+            id,
+            root: Note::One,
+            relative: relative.into(),
+            revealed: false,
+        })
+    }
+
+    #[test]
+    fn test_quiz_scores_each_exercise_once() {
+        let mut quiz = Quiz::new();
+
+        assert_eq!(
+            quiz.answer(exercise(1, Note::FlatThree), Note::FlatThree),
+            AnswerOutcome::Correct
+        );
+        assert_eq!(
+            quiz.answer(exercise(1, Note::FlatThree), Note::Three),
+            AnswerOutcome::AlreadyAnswered
+        );
+        assert_eq!(
+            quiz.answer(exercise(2, Note::Five), Note::Four),
+            AnswerOutcome::Incorrect {
+                expected: Note::Five.into()
+            }
+        );
+        assert_eq!(
+            quiz.score(),
+            Score {
+                correct: 1,
+                answered: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_quiz_with_quarter_tones() {
+        let mut quiz = Quiz::new();
+        let neutral_third = Degree::new(350).unwrap();
+        let exercise = Some(ExerciseInfo {
+            id: 1,
+            root: Note::One,
+            relative: neutral_third,
+            revealed: false,
+        });
+
+        assert_eq!(
+            quiz.answer(exercise, Note::FlatThree),
+            AnswerOutcome::Incorrect {
+                expected: neutral_third
+            }
+        );
+        assert_eq!(
+            Quiz::new().answer(exercise, neutral_third),
+            AnswerOutcome::Correct
+        );
+    }
+
+    #[test]
+    fn test_quiz_ignores_answers_after_the_reveal() {
+        let mut quiz = Quiz::new();
+        let revealed = exercise(1, Note::Six).map(|exercise| ExerciseInfo {
+            revealed: true,
+            ..exercise
+        });
+
+        assert_eq!(quiz.answer(revealed, Note::Six), AnswerOutcome::Revealed);
+        assert_eq!(quiz.score(), Score::default());
+
+        // An answer given in time still counts once
+        assert_eq!(
+            quiz.answer(exercise(2, Note::Six), Note::Six),
+            AnswerOutcome::Correct
+        );
+        let revealed = exercise(2, Note::Six).map(|exercise| ExerciseInfo {
+            revealed: true,
+            ..exercise
+        });
+        assert_eq!(
+            quiz.answer(revealed, Note::Six),
+            AnswerOutcome::AlreadyAnswered
+        );
+    }
+
+    #[test]
+    fn test_quiz_without_exercise() {
+        let mut quiz = Quiz::new();
+
+        assert_eq!(quiz.answer(None, Note::One), AnswerOutcome::NoExercise);
+        assert_eq!(quiz.score(), Score::default());
+    }
+}
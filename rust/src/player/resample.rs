@@ -0,0 +1,117 @@
+use std::f32::consts::PI;
+
+// Zero crossings of the sinc kernel on each side of a sample
+const KERNEL_HALF_WIDTH: usize = 16;
+
+/// Band limited resampling with a Hann windowed sinc kernel.
+///
+/// Meant for loading assets, not for the audio thread: it allocates the
+/// output and costs `2 * KERNEL_HALF_WIDTH` multiplies per output sample.
+/// When downsampling the cutoff follows the lower rate so nothing aliases.
+pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let step = from_rate as f64 / to_rate as f64;
+    let cutoff = (to_rate as f32 / from_rate as f32).min(1.0);
+    let half_width = (KERNEL_HALF_WIDTH as f32 / cutoff).ceil() as isize;
+    let output_len = (samples.len() as f64 / step).round() as usize;
+
+    (0..output_len)
+        .map(|i| {
+            let position = i as f64 * step;
+            let center = position.floor() as isize;
+            let fraction = (position - center as f64) as f32;
+
+            let mut sum = 0.0;
+            let mut weights = 0.0;
+            for offset in -half_width + 1..=half_width {
+                let index = center + offset;
+                if index < 0 || index >= samples.len() as isize {
+                    continue;
+                }
+                let x = offset as f32 - fraction;
+                let weight = cutoff * sinc(cutoff * x) * hann(x, half_width as f32);
+                sum += samples[index as usize] * weight;
+                weights += weight;
+            }
+            // Normalizing keeps DC level, including at the edges
+            if weights.abs() > f32::EPSILON {
+                sum / weights
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+fn sinc(x: f32) -> f32 {
+    if x.abs() < 1e-6 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+fn hann(x: f32, half_width: f32) -> f32 {
+    if x.abs() >= half_width {
+        0.0
+    } else {
+        0.5 + 0.5 * (PI * x / half_width).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn sine(frequency: f32, sample_rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    fn max_error(a: &[f32], b: &[f32]) -> f32 {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_same_rate_is_a_copy() {
+        let samples = sine(440.0, 48000, 100);
+        assert_eq!(resample(&samples, 48000, 48000), samples);
+    }
+
+    #[test]
+    fn test_upsampling_keeps_the_waveform() {
+        let resampled = resample(&sine(440.0, 44100, 4410), 44100, 48000);
+        assert_eq!(resampled.len(), 4800);
+
+        // Away from the edges, where the kernel is cut short
+        let expected = sine(440.0, 48000, 4800);
+        assert!(max_error(&resampled[100..4700], &expected[100..4700]) < 1e-2);
+    }
+
+    #[test]
+    fn test_downsampling_keeps_the_waveform() {
+        let resampled = resample(&sine(1000.0, 96000, 9600), 96000, 48000);
+        assert_eq!(resampled.len(), 4800);
+
+        let expected = sine(1000.0, 48000, 4800);
+        assert!(max_error(&resampled[100..4700], &expected[100..4700]) < 1e-2);
+    }
+
+    #[test]
+    fn test_downsampling_removes_frequencies_above_nyquist() {
+        // 30 kHz fits at 96 kHz but would fold back to 18 kHz at 48 kHz
+        let resampled = resample(&sine(30000.0, 96000, 9600), 96000, 48000);
+        let peak = resampled[100..4700]
+            .iter()
+            .fold(0.0f32, |max, s| max.max(s.abs()));
+        assert!(peak < 0.05, "aliasing peak {}", peak);
+    }
+}
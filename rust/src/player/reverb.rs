@@ -0,0 +1,306 @@
+use crate::player::atomic::AtomicF32;
+use crate::player::constants::SAMPLE_RATE;
+
+// Freeverb tunings, in samples at 44.1 kHz
+const COMB_TUNINGS: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+const ALLPASS_TUNINGS: [usize; 4] = [556, 441, 341, 225];
+const STEREO_SPREAD: usize = 23;
+const TUNING_SAMPLE_RATE: f32 = 44100.0;
+
+const FIXED_GAIN: f32 = 0.015;
+const SCALE_WET: f32 = 3.0;
+const SCALE_ROOM: f32 = 0.28;
+const OFFSET_ROOM: f32 = 0.7;
+const SCALE_DAMPING: f32 = 0.4;
+const ALLPASS_FEEDBACK: f32 = 0.5;
+const WET_SMOOTHING_SECONDS: f32 = 0.02;
+
+static SETTINGS: ReverbSettings = ReverbSettings::new();
+
+pub fn settings() -> &'static ReverbSettings {
+    &SETTINGS
+}
+
+/// Reverb parameters shared with the audio thread, all in the 0.0..=1.0 range.
+pub struct ReverbSettings {
+    room_size: AtomicF32,
+    damping: AtomicF32,
+    wet: AtomicF32,
+}
+
+impl ReverbSettings {
+    const fn new() -> Self {
+        ReverbSettings {
+            room_size: AtomicF32::new(0.5),
+            damping: AtomicF32::new(0.5),
+            wet: AtomicF32::new(0.0),
+        }
+    }
+
+    pub fn room_size(&self) -> f32 {
+        self.room_size.load()
+    }
+
+    pub fn set_room_size(&self, room_size: f32) {
+        self.room_size.store(clamp_unit(room_size));
+    }
+
+    pub fn damping(&self) -> f32 {
+        self.damping.load()
+    }
+
+    pub fn set_damping(&self, damping: f32) {
+        self.damping.store(clamp_unit(damping));
+    }
+
+    /// Wet/dry balance, 0.0 leaves the signal dry.
+    pub fn wet(&self) -> f32 {
+        self.wet.load()
+    }
+
+    pub fn set_wet(&self, wet: f32) {
+        self.wet.store(clamp_unit(wet));
+    }
+}
+
+fn clamp_unit(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+fn scaled_length(tuning: usize, sample_rate: u32) -> usize {
+    ((tuning as f32 * sample_rate as f32 / TUNING_SAMPLE_RATE) as usize).max(1)
+}
+
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filter_store: f32,
+}
+
+impl Comb {
+    fn new(length: usize) -> Self {
+        Comb {
+            buffer: vec![0.0; length],
+            index: 0,
+            filter_store: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.filter_store = output * (1.0 - damping) + self.filter_store * damping;
+        self.buffer[self.index] = input + self.filter_store * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+struct Allpass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl Allpass {
+    fn new(length: usize) -> Self {
+        Allpass {
+            buffer: vec![0.0; length],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let buffered = self.buffer[self.index];
+        self.buffer[self.index] = input + buffered * ALLPASS_FEEDBACK;
+        self.index = (self.index + 1) % self.buffer.len();
+        buffered - input
+    }
+}
+
+struct ReverbChannel {
+    combs: Vec<Comb>,
+    allpasses: Vec<Allpass>,
+}
+
+impl ReverbChannel {
+    fn new(sample_rate: u32, spread: usize) -> Self {
+        ReverbChannel {
+            combs: COMB_TUNINGS
+                .iter()
+                .map(|&tuning| Comb::new(scaled_length(tuning + spread, sample_rate)))
+                .collect(),
+            allpasses: ALLPASS_TUNINGS
+                .iter()
+                .map(|&tuning| Allpass::new(scaled_length(tuning + spread, sample_rate)))
+                .collect(),
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let mut output = 0.0;
+        for comb in self.combs.iter_mut() {
+            output += comb.process(input, feedback, damping);
+        }
+        for allpass in self.allpasses.iter_mut() {
+            output = allpass.process(output);
+        }
+        output
+    }
+}
+
+/// Freeverb style algorithmic reverb.
+///
+/// All delay lines are allocated up front, processing a frame never allocates
+/// so it can run inside the audio callback.
+pub struct Reverb {
+    left: ReverbChannel,
+    right: ReverbChannel,
+    wet: f32,
+    smoothing: f32,
+}
+
+impl Reverb {
+    pub fn new() -> Self {
+        Self::with_sample_rate(SAMPLE_RATE)
+    }
+
+    pub fn with_sample_rate(sample_rate: u32) -> Self {
+        Self::with_settings(sample_rate, settings())
+    }
+
+    fn with_settings(sample_rate: u32, settings: &ReverbSettings) -> Self {
+        Reverb {
+            left: ReverbChannel::new(sample_rate, 0),
+            right: ReverbChannel::new(sample_rate, STEREO_SPREAD),
+            wet: settings.wet(),
+            smoothing: 1.0 - (-1.0 / (WET_SMOOTHING_SECONDS * sample_rate as f32)).exp(),
+        }
+    }
+
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        self.process_with(left, right, settings())
+    }
+
+    fn process_with(&mut self, left: f32, right: f32, settings: &ReverbSettings) -> (f32, f32) {
+        self.wet += (settings.wet() - self.wet) * self.smoothing;
+
+        let feedback = settings.room_size() * SCALE_ROOM + OFFSET_ROOM;
+        let damping = settings.damping() * SCALE_DAMPING;
+        let input = (left + right) * FIXED_GAIN;
+
+        let wet_left = self.left.process(input, feedback, damping) * SCALE_WET;
+        let wet_right = self.right.process(input, feedback, damping) * SCALE_WET;
+
+        (
+            left * (1.0 - self.wet) + wet_left * self.wet,
+            right * (1.0 - self.wet) + wet_right * self.wet,
+        )
+    }
+}
+
+impl Default for Reverb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+    use test_log::test;
+
+    fn reverb_settings(room_size: f32, damping: f32, wet: f32) -> ReverbSettings {
+        let settings = ReverbSettings::new();
+        settings.set_room_size(room_size);
+        settings.set_damping(damping);
+        settings.set_wet(wet);
+        settings
+    }
+
+    // Energy of the left output over consecutive windows of `window` frames
+    fn window_energies(output: &[(f32, f32)], window: usize) -> Vec<f32> {
+        output
+            .chunks(window)
+            .map(|chunk| chunk.iter().map(|(left, _)| left * left).sum())
+            .collect()
+    }
+
+    #[test]
+    fn test_dry_reverb_passes_the_input_through() {
+        let settings = reverb_settings(1.0, 0.0, 0.0);
+        let mut reverb = Reverb::with_settings(SAMPLE_RATE, &settings);
+        for i in 0..SAMPLE_RATE {
+            let sample = 0.8 * (2.0 * PI * 440.0 * i as f32 / SAMPLE_RATE as f32).sin();
+            assert_eq!(
+                reverb.process_with(sample, -sample, &settings),
+                (sample, -sample)
+            );
+        }
+    }
+
+    #[test]
+    fn test_impulse_response_decays() {
+        let settings = reverb_settings(0.5, 0.5, 1.0);
+        let mut reverb = Reverb::with_settings(SAMPLE_RATE, &settings);
+        let output: Vec<(f32, f32)> = (0..3 * SAMPLE_RATE)
+            .map(|i| {
+                let impulse = if i == 0 { 1.0 } else { 0.0 };
+                reverb.process_with(impulse, impulse, &settings)
+            })
+            .collect();
+
+        // Quarter second windows, the first one holds the early reflections
+        let energies = window_energies(&output, SAMPLE_RATE as usize / 4);
+        assert!(energies[0] > 0.0);
+        assert!(
+            energies.windows(2).all(|w| w[1] < w[0]),
+            "energy does not decay: {:?}",
+            energies
+        );
+        assert!(energies.last().unwrap() / energies[0] < 1e-4);
+    }
+
+    #[test]
+    fn test_largest_room_keeps_a_finite_bounded_tail() {
+        let settings = reverb_settings(1.0, 0.0, 1.0);
+        let mut reverb = Reverb::with_settings(SAMPLE_RATE, &settings);
+        // A second of full scale square wave, then silence
+        let output: Vec<(f32, f32)> = (0..8 * SAMPLE_RATE)
+            .map(|i| {
+                let input = if i < SAMPLE_RATE {
+                    if i / 50 % 2 == 0 {
+                        1.0
+                    } else {
+                        -1.0
+                    }
+                } else {
+                    0.0
+                };
+                reverb.process_with(input, input, &settings)
+            })
+            .collect();
+
+        assert!(output
+            .iter()
+            .all(|(left, right)| left.is_finite() && right.is_finite()));
+        let peak = output.iter().fold(0.0f32, |peak, (left, right)| {
+            peak.max(left.abs()).max(right.abs())
+        });
+        // Feedback below one caps every comb at 1 / (1 - feedback) times its input
+        let feedback = SCALE_ROOM + OFFSET_ROOM;
+        let comb_bound = 2.0 * FIXED_GAIN / (1.0 - feedback);
+        let bound = COMB_TUNINGS.len() as f32 * comb_bound * SCALE_WET;
+        assert!(peak < bound, "peak {} above {}", peak, bound);
+
+        let energies = window_energies(&output[SAMPLE_RATE as usize..], SAMPLE_RATE as usize);
+        assert!(
+            energies.windows(2).all(|w| w[1] < w[0]),
+            "tail does not decay: {:?}",
+            energies
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::api::degrees::Degree;
+use crate::api::notes::Note;
+
+// Packed as active flag | revealed flag | id | root | relative cents, so the
+// audio thread can publish a new exercise with a single store
+static CURRENT_EXERCISE: AtomicU64 = AtomicU64::new(0);
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+const ACTIVE: u64 = 1 << 63;
+const REVEALED: u64 = 1 << 62;
+
+/// The exercise currently being played.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExerciseInfo {
+    /// Changes every time a new exercise starts.
+    pub id: u32,
+    pub root: Note,
+    pub relative: Degree,
+    /// The voice has started speaking the answer.
+    pub revealed: bool,
+}
+
+pub fn current_exercise() -> Option<ExerciseInfo> {
+    let packed = CURRENT_EXERCISE.load(Ordering::Acquire);
+    if packed & ACTIVE == 0 {
+        return None;
+    }
+    Some(ExerciseInfo {
+        id: (packed >> 24) as u32,
+        root: Note::try_from((packed >> 16) as u8).ok()?,
+        relative: Degree::new(packed as u16).ok()?,
+        revealed: packed & REVEALED != 0,
+    })
+}
+
+pub(crate) fn publish(root: Note, relative: Degree) {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed) as u32;
+    let packed =
+        ACTIVE | (id as u64) << 24 | (root.semitones() as u64) << 16 | relative.cents() as u64;
+    CURRENT_EXERCISE.store(packed, Ordering::Release);
+}
+
+/// Marks the current exercise as answered by the voice.
+pub(crate) fn reveal() {
+    CURRENT_EXERCISE.fetch_or(REVEALED, Ordering::Release);
+}
+
+pub(crate) fn clear() {
+    CURRENT_EXERCISE.store(0, Ordering::Release);
+}
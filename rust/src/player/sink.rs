@@ -0,0 +1,114 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::Player;
+
+/// Destination for the rendered audio.
+///
+/// A sink takes ownership of a `Player` and pulls interleaved stereo buffers
+/// from it until it is stopped or handed a new player.
+pub trait AudioSink: Send {
+    fn start(&mut self, player: Player);
+    fn stop(&mut self);
+}
+
+/// Plays through the default cpal output device.
+#[derive(Default)]
+pub struct CpalSink {
+    sender: Option<mpsc::Sender<()>>,
+}
+
+impl CpalSink {
+    pub fn new() -> Self {
+        CpalSink { sender: None }
+    }
+}
+
+impl AudioSink for CpalSink {
+    fn start(&mut self, mut player: Player) {
+        self.stop();
+
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let host = cpal::default_host();
+            let device = host
+                .default_output_device()
+                .expect("No output device available");
+            let config = cpal::StreamConfig {
+                channels: 2,
+                sample_rate: cpal::SampleRate(SAMPLE_RATE),
+                buffer_size: cpal::BufferSize::Default,
+            };
+
+            let stream = device
+                .build_output_stream(
+                    &config,
+                    move |data: &mut [f32], _| player.render(data),
+                    err_fn,
+                )
+                .unwrap();
+
+            stream.play().unwrap();
+            let _ = rx.recv().is_ok();
+        });
+
+        self.sender = Some(tx);
+    }
+
+    fn stop(&mut self) {
+        if let Some(sender) = self.sender.take() {
+            let _ = sender.send(());
+        }
+    }
+}
+
+fn err_fn(err: cpal::StreamError) {
+    eprintln!("Error: {:?}", err);
+}
+
+/// In-memory sink that renders only when asked to.
+///
+/// Clones share the same player, so a test can hand one clone to the manager
+/// and keep another to pull audio from.
+#[derive(Clone, Default)]
+pub struct MemorySink {
+    player: Arc<Mutex<Option<Player>>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Self {
+        MemorySink {
+            player: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.player.lock().unwrap().is_some()
+    }
+
+    /// Renders `frames` stereo frames, interleaved. Silence when stopped.
+    pub fn pull(&self, frames: usize) -> Vec<f32> {
+        let mut data = vec![0.0; frames * 2];
+        if let Some(player) = self.player.lock().unwrap().as_mut() {
+            player.render(&mut data);
+        }
+        data
+    }
+
+    pub fn pull_seconds(&self, seconds: f32) -> Vec<f32> {
+        self.pull((seconds * SAMPLE_RATE as f32) as usize)
+    }
+}
+
+impl AudioSink for MemorySink {
+    fn start(&mut self, player: Player) {
+        *self.player.lock().unwrap() = Some(player);
+    }
+
+    fn stop(&mut self) {
+        *self.player.lock().unwrap() = None;
+    }
+}
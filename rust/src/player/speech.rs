@@ -0,0 +1,518 @@
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use lazy_static::lazy_static;
+
+use crate::api::notes::{Note, ALL_NOTES};
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::wav::WavFile;
+
+// Pitch falls over the utterance like a short statement
+const PITCH_START: f32 = 130.0;
+const PITCH_END: f32 = 95.0;
+const PEAK_LEVEL: f32 = 0.7;
+// Part of each phoneme spent gliding from the previous formants
+const TRANSITION: f32 = 0.35;
+const RAMP_SECONDS: f32 = 0.008;
+const WORD_GAP_SECONDS: f32 = 0.06;
+
+lazy_static! {
+    static ref LEXICON: HashMap<&'static str, &'static str> = HashMap::from([
+        ("one", "w ah n"),
+        ("two", "t uw"),
+        ("three", "th r iy"),
+        ("four", "f ao r"),
+        ("five", "f ay v"),
+        ("six", "s ih k s"),
+        ("seven", "s eh v ah n"),
+        ("eight", "ey t"),
+        ("flat", "f l ae t"),
+        ("sharp", "sh aa r p"),
+        ("natural", "n ae ch er ah l"),
+        ("major", "m ey jh er"),
+        ("minor", "m ay n er"),
+        ("chord", "k ao r d"),
+        ("root", "r uw t"),
+        ("third", "th er d"),
+        ("fifth", "f ih f th"),
+        ("octave", "aa k t ih v"),
+        ("tone", "t ow n"),
+        ("and", "ae n d"),
+        ("the", "dh ah"),
+        ("do", "d ow"),
+        ("di", "d iy"),
+        ("ra", "r aa"),
+        ("re", "r ey"),
+        ("ri", "r iy"),
+        ("me", "m ey"),
+        ("mi", "m iy"),
+        ("fa", "f aa"),
+        ("fi", "f iy"),
+        ("se", "s ey"),
+        ("sol", "s ow l"),
+        ("si", "s iy"),
+        ("le", "l ey"),
+        ("la", "l aa"),
+        ("li", "l iy"),
+        ("te", "t ey"),
+        ("ti", "t iy"),
+    ]);
+}
+
+// Letter groups to phonemes for words missing from the lexicon, longest first
+const SPELLING_RULES: &[(&str, &str)] = &[
+    ("tch", "ch"),
+    ("sh", "sh"),
+    ("ch", "ch"),
+    ("th", "th"),
+    ("ph", "f"),
+    ("ng", "ng"),
+    ("ck", "k"),
+    ("qu", "k w"),
+    ("ee", "iy"),
+    ("ea", "iy"),
+    ("oo", "uw"),
+    ("ou", "aw"),
+    ("ow", "ow"),
+    ("oa", "ow"),
+    ("ai", "ey"),
+    ("ay", "ey"),
+    ("oi", "oy"),
+    ("oy", "oy"),
+    ("a", "ae"),
+    ("b", "b"),
+    ("c", "k"),
+    ("d", "d"),
+    ("e", "eh"),
+    ("f", "f"),
+    ("g", "g"),
+    ("h", "hh"),
+    ("i", "ih"),
+    ("j", "jh"),
+    ("k", "k"),
+    ("l", "l"),
+    ("m", "m"),
+    ("n", "n"),
+    ("o", "aa"),
+    ("p", "p"),
+    ("r", "r"),
+    ("s", "s"),
+    ("t", "t"),
+    ("u", "ah"),
+    ("v", "v"),
+    ("w", "w"),
+    ("x", "k s"),
+    ("y", "iy"),
+    ("z", "z"),
+];
+
+/// How a phoneme sounds: formants of the voiced part and a noise band.
+#[derive(Debug, Copy, Clone)]
+struct Phone {
+    formants: [f32; 3],
+    seconds: f32,
+    voicing: f32,
+    noise: f32,
+    noise_frequency: f32,
+    noise_bandwidth: f32,
+}
+
+const fn vowel(f1: f32, f2: f32, f3: f32, seconds: f32) -> Phone {
+    Phone {
+        formants: [f1, f2, f3],
+        seconds,
+        voicing: 1.0,
+        noise: 0.0,
+        noise_frequency: 0.0,
+        noise_bandwidth: 0.0,
+    }
+}
+
+const fn voiced(f1: f32, f2: f32, f3: f32, seconds: f32, voicing: f32) -> Phone {
+    Phone {
+        voicing,
+        ..vowel(f1, f2, f3, seconds)
+    }
+}
+
+const fn noise(frequency: f32, bandwidth: f32, seconds: f32, level: f32, voicing: f32) -> Phone {
+    Phone {
+        formants: [500.0, 1500.0, 2500.0],
+        seconds,
+        voicing,
+        noise: level,
+        noise_frequency: frequency,
+        noise_bandwidth: bandwidth,
+    }
+}
+
+const SILENCE: Phone = voiced(500.0, 1500.0, 2500.0, 0.05, 0.0);
+
+/// Phones of one phoneme symbol, diphthongs and stops take more than one.
+fn phones(symbol: &str) -> Result<Vec<Phone>, String> {
+    let closure = |seconds| Phone { seconds, ..SILENCE };
+    Ok(match symbol {
+        "iy" => vec![vowel(270.0, 2290.0, 3010.0, 0.16)],
+        "ih" => vec![vowel(390.0, 1990.0, 2550.0, 0.10)],
+        "eh" => vec![vowel(530.0, 1840.0, 2480.0, 0.11)],
+        "ae" => vec![vowel(660.0, 1720.0, 2410.0, 0.14)],
+        "aa" => vec![vowel(730.0, 1090.0, 2440.0, 0.16)],
+        "ao" => vec![vowel(570.0, 840.0, 2410.0, 0.16)],
+        "uh" => vec![vowel(440.0, 1020.0, 2240.0, 0.10)],
+        "uw" => vec![vowel(300.0, 870.0, 2240.0, 0.16)],
+        "ah" => vec![vowel(640.0, 1190.0, 2390.0, 0.09)],
+        "er" => vec![vowel(490.0, 1350.0, 1690.0, 0.14)],
+        "ey" => vec![
+            vowel(480.0, 1900.0, 2500.0, 0.10),
+            vowel(300.0, 2200.0, 2900.0, 0.08),
+        ],
+        "ay" => vec![
+            vowel(730.0, 1150.0, 2450.0, 0.12),
+            vowel(350.0, 2100.0, 2800.0, 0.08),
+        ],
+        "ow" => vec![
+            vowel(500.0, 900.0, 2400.0, 0.11),
+            vowel(320.0, 800.0, 2250.0, 0.08),
+        ],
+        "aw" => vec![
+            vowel(730.0, 1150.0, 2450.0, 0.11),
+            vowel(320.0, 800.0, 2250.0, 0.08),
+        ],
+        "oy" => vec![
+            vowel(570.0, 840.0, 2410.0, 0.11),
+            vowel(350.0, 2100.0, 2800.0, 0.08),
+        ],
+        "w" => vec![voiced(290.0, 610.0, 2150.0, 0.06, 0.8)],
+        "y" => vec![voiced(260.0, 2070.0, 3020.0, 0.06, 0.8)],
+        "r" => vec![voiced(310.0, 1060.0, 1380.0, 0.07, 0.8)],
+        "l" => vec![voiced(310.0, 1050.0, 2880.0, 0.07, 0.7)],
+        "m" => vec![voiced(250.0, 1100.0, 2130.0, 0.07, 0.4)],
+        "n" => vec![voiced(250.0, 1600.0, 2470.0, 0.07, 0.4)],
+        "ng" => vec![voiced(250.0, 2000.0, 2700.0, 0.07, 0.4)],
+        "hh" => vec![noise(1500.0, 3000.0, 0.06, 0.3, 0.0)],
+        "f" => vec![noise(5000.0, 5000.0, 0.10, 0.25, 0.0)],
+        "th" => vec![noise(5500.0, 6000.0, 0.09, 0.18, 0.0)],
+        "s" => vec![noise(6500.0, 3000.0, 0.11, 0.7, 0.0)],
+        "sh" => vec![noise(3000.0, 2000.0, 0.11, 0.7, 0.0)],
+        "v" => vec![noise(5000.0, 5000.0, 0.07, 0.15, 0.4)],
+        "dh" => vec![noise(5500.0, 6000.0, 0.05, 0.1, 0.4)],
+        "z" => vec![noise(6500.0, 3000.0, 0.08, 0.4, 0.4)],
+        "zh" => vec![noise(3000.0, 2000.0, 0.08, 0.4, 0.4)],
+        "p" => vec![closure(0.06), noise(1000.0, 2000.0, 0.02, 0.5, 0.0)],
+        "b" => vec![closure(0.04), noise(1000.0, 2000.0, 0.015, 0.3, 0.3)],
+        "t" => vec![closure(0.05), noise(4000.0, 3000.0, 0.025, 0.6, 0.0)],
+        "d" => vec![closure(0.04), noise(4000.0, 3000.0, 0.015, 0.4, 0.3)],
+        "k" => vec![closure(0.06), noise(2000.0, 1500.0, 0.03, 0.6, 0.0)],
+        "g" => vec![closure(0.04), noise(2000.0, 1500.0, 0.02, 0.4, 0.3)],
+        "ch" => vec![closure(0.05), noise(3000.0, 2000.0, 0.08, 0.7, 0.0)],
+        "jh" => vec![closure(0.04), noise(3000.0, 2000.0, 0.06, 0.4, 0.4)],
+        _ => return Err(format!("Unknown phoneme: {}", symbol)),
+    })
+}
+
+/// Words for the digits and accidentals in degree names like `b3` or `#4`.
+fn normalize(text: &str) -> Vec<String> {
+    let digit = |c: char| match c {
+        '1' => Some("one"),
+        '2' => Some("two"),
+        '3' => Some("three"),
+        '4' => Some("four"),
+        '5' => Some("five"),
+        '6' => Some("six"),
+        '7' => Some("seven"),
+        '8' => Some("eight"),
+        _ => None,
+    };
+
+    let mut words = Vec::new();
+    for token in text
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '-')
+        .filter(|token| !token.is_empty())
+    {
+        let mut rest = token;
+        loop {
+            if let Some(tail) = rest
+                .strip_prefix(['b', '♭'])
+                .filter(|t| t.starts_with(digit_char))
+            {
+                words.push("flat".to_string());
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix(['#', '♯']) {
+                words.push("sharp".to_string());
+                rest = tail;
+            } else {
+                break;
+            }
+        }
+        let mut word = String::new();
+        for c in rest.chars() {
+            if let Some(name) = digit(c) {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+                words.push(name.to_string());
+            } else if c.is_ascii_alphabetic() {
+                word.push(c);
+            }
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn digit_char(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+fn spell(word: &str) -> Vec<&'static str> {
+    let mut symbols = Vec::new();
+    let mut rest = word;
+    while !rest.is_empty() {
+        match SPELLING_RULES
+            .iter()
+            .find(|(letters, _)| rest.starts_with(letters))
+        {
+            Some((letters, sounds)) => {
+                symbols.extend(sounds.split(' '));
+                rest = &rest[letters.len()..];
+            }
+            // Anything else is silent
+            None => rest = &rest[rest.chars().next().map_or(1, char::len_utf8)..],
+        }
+    }
+    symbols
+}
+
+fn text_to_phones(text: &str) -> Result<Vec<Phone>, String> {
+    let words = normalize(text);
+    if words.is_empty() {
+        return Err(format!("Nothing to say in: {:?}", text));
+    }
+
+    let mut all = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            all.push(Phone {
+                seconds: WORD_GAP_SECONDS,
+                ..SILENCE
+            });
+        }
+        let symbols: Vec<&str> = match LEXICON.get(word.as_str()) {
+            Some(pronunciation) => pronunciation.split(' ').collect(),
+            None => spell(word),
+        };
+        for symbol in symbols {
+            all.extend(phones(symbol)?);
+        }
+    }
+    Ok(all)
+}
+
+/// Two-pole resonator as in the Klatt synthesizer.
+#[derive(Default)]
+struct Resonator {
+    y1: f32,
+    y2: f32,
+}
+
+impl Resonator {
+    fn process(&mut self, input: f32, frequency: f32, bandwidth: f32) -> f32 {
+        let t = 1.0 / SAMPLE_RATE as f32;
+        let c = -(-2.0 * PI * bandwidth * t).exp();
+        let b = 2.0 * (-PI * bandwidth * t).exp() * (2.0 * PI * frequency * t).cos();
+        let a = 1.0 - b - c;
+        let output = a * input + b * self.y1 + c * self.y2;
+        self.y2 = self.y1;
+        self.y1 = output;
+        output
+    }
+}
+
+fn formant_bandwidth(formant: usize) -> f32 {
+    [80.0, 100.0, 140.0][formant]
+}
+
+// Rosenberg glottal pulse, open for 40% and closing for 16% of the period
+fn glottal_pulse(phase: f32) -> f32 {
+    if phase < 0.4 {
+        0.5 * (1.0 - (PI * phase / 0.4).cos())
+    } else if phase < 0.56 {
+        (0.5 * PI * (phase - 0.4) / 0.16).cos()
+    } else {
+        0.0
+    }
+}
+
+fn lerp(from: f32, to: f32, amount: f32) -> f32 {
+    from + (to - from) * amount
+}
+
+/// Speaks `text` with a small formant synthesizer, mono at `SAMPLE_RATE`.
+///
+/// Words come from a lexicon of degree and solfège names with a letter to
+/// sound fallback, so anything can be said but only the lexicon is clear.
+/// Degree names like `b6` are read as "flat six".
+pub fn speak(text: &str) -> Result<Vec<f32>, String> {
+    let phones = text_to_phones(text)?;
+    let sample_rate = SAMPLE_RATE as f32;
+    let total: usize = phones
+        .iter()
+        .map(|phone| (phone.seconds * sample_rate) as usize)
+        .sum();
+
+    let mut output = Vec::with_capacity(total);
+    let mut cascade: [Resonator; 3] = Default::default();
+    let mut frication = Resonator::default();
+    let mut phase = 0.0f32;
+    let mut previous_pulse = 0.0f32;
+    let mut seed = 0x2545_f491_u32;
+    let mut previous = SILENCE;
+    let mut voicing = 0.0f32;
+    let mut noise_level = 0.0f32;
+    let ramp = 1.0 / (RAMP_SECONDS * sample_rate);
+
+    for phone in &phones {
+        let len = (phone.seconds * sample_rate) as usize;
+        for i in 0..len {
+            let progress = i as f32 / len as f32;
+            let glide = (progress / TRANSITION).min(1.0);
+            let pitch = lerp(PITCH_START, PITCH_END, output.len() as f32 / total as f32);
+
+            // Levels move in short ramps so phoneme boundaries don't click
+            voicing += (phone.voicing - voicing).clamp(-ramp, ramp);
+            noise_level += (phone.noise - noise_level).clamp(-ramp, ramp);
+
+            phase = (phase + pitch / sample_rate).fract();
+            let pulse = glottal_pulse(phase);
+            // Differentiating the pulse stands in for the lip radiation
+            let source = (pulse - previous_pulse) * voicing;
+            previous_pulse = pulse;
+
+            let mut voiced = source;
+            for (formant, resonator) in cascade.iter_mut().enumerate() {
+                let frequency = lerp(previous.formants[formant], phone.formants[formant], glide);
+                voiced = resonator.process(voiced, frequency, formant_bandwidth(formant));
+            }
+
+            // xorshift white noise, deterministic so voices are reproducible
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let white = seed as f32 / u32::MAX as f32 * 2.0 - 1.0;
+            let fricative = if phone.noise > 0.0 {
+                frication.process(white, phone.noise_frequency, phone.noise_bandwidth)
+            } else {
+                frication.process(0.0, 1000.0, 1000.0)
+            };
+
+            output.push(voiced + fricative * noise_level);
+        }
+        previous = *phone;
+    }
+
+    let peak = output.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+    if peak > 0.0 {
+        for sample in output.iter_mut() {
+            *sample *= PEAK_LEVEL / peak;
+        }
+    }
+    Ok(output)
+}
+
+/// How a degree is read out, e.g. "flat six".
+pub fn degree_words(note: Note) -> String {
+    normalize(&note.to_string()).join(" ")
+}
+
+lazy_static! {
+    static ref SPOKEN_DEGREES: HashMap<i32, WavFile> = ALL_NOTES
+        .iter()
+        .map(|note| {
+            let samples = speak(&degree_words(*note)).expect("degree names can be spoken");
+            (note.to_keyboard_note(), WavFile::from_samples(samples))
+        })
+        .collect();
+}
+
+/// Synthesized voices for every degree, keyed like `Note::to_keyboard_note`.
+///
+/// Rendered once on first use and shared afterwards.
+pub fn spoken_degrees() -> &'static HashMap<i32, WavFile> {
+    &SPOKEN_DEGREES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    // Energy of `frequency` in `samples`, with the Goertzel algorithm
+    fn energy_at(samples: &[f32], frequency: f32) -> f32 {
+        let coefficient = 2.0 * (2.0 * PI * frequency / SAMPLE_RATE as f32).cos();
+        let (mut s1, mut s2) = (0.0f32, 0.0f32);
+        for sample in samples {
+            let s0 = sample + coefficient * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        s1 * s1 + s2 * s2 - coefficient * s1 * s2
+    }
+
+    // Energy between `low` and `high` Hz relative to the whole signal
+    fn band_energy(samples: &[f32], low: f32, high: f32) -> f32 {
+        let total: f32 = samples.iter().map(|s| s * s).sum();
+        let mut frequency = low;
+        let mut energy = 0.0;
+        while frequency <= high {
+            energy += energy_at(samples, frequency);
+            frequency += 10.0;
+        }
+        energy / total
+    }
+
+    #[test]
+    fn test_degree_words() {
+        assert_eq!(degree_words(Note::One), "one");
+        assert_eq!(degree_words(Note::FlatSix), "flat six");
+        assert_eq!(degree_words(Note::SharpFour), "sharp four");
+        assert_eq!(
+            normalize("b3, #4 and 5"),
+            vec!["flat", "three", "sharp", "four", "and", "five"]
+        );
+        assert_eq!(normalize("Major chord"), vec!["major", "chord"]);
+        assert_eq!(normalize("bass"), vec!["bass"]);
+    }
+
+    #[test]
+    fn test_speaks_degrees_and_new_words() {
+        for text in ["flat six", "major chord", "sol", "quarter tone", "zebra"] {
+            let samples = speak(text).unwrap();
+            let seconds = samples.len() as f32 / SAMPLE_RATE as f32;
+            assert!((0.2..2.5).contains(&seconds), "{}: {}s", text, seconds);
+            assert!(samples
+                .iter()
+                .all(|s| s.is_finite() && s.abs() <= PEAK_LEVEL + 1e-6));
+        }
+        assert!(speak("  ").is_err());
+    }
+
+    #[test]
+    fn test_vowels_have_their_formants() {
+        let ee = speak("ee").unwrap();
+        let oo = speak("oo").unwrap();
+        // "ee" has its second formant near 2300 Hz, "oo" near 870 Hz
+        assert!(band_energy(&ee, 2000.0, 2600.0) > 4.0 * band_energy(&oo, 2000.0, 2600.0));
+        assert!(band_energy(&oo, 700.0, 1000.0) > 4.0 * band_energy(&ee, 700.0, 1000.0));
+    }
+
+    #[test]
+    fn test_spoken_degrees_cover_every_degree() {
+        for note in ALL_NOTES {
+            let voice = &spoken_degrees()[&note.to_keyboard_note()];
+            assert!(!voice.is_empty());
+        }
+    }
+}
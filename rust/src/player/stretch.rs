@@ -0,0 +1,145 @@
+use std::f32::consts::PI;
+
+// 32 ms frames at 48 kHz, long enough to hold a few periods of a low voice
+const FRAME: usize = 1536;
+const HOP: usize = FRAME / 2;
+// How far a frame may move from its nominal position to line up with the last one
+const TOLERANCE: isize = 384;
+// Every 4th sample is enough to find the best alignment
+const CORRELATION_STRIDE: usize = 4;
+
+/// Changes the duration by `factor` without changing the pitch, using WSOLA
+/// (waveform similarity overlap-add).
+///
+/// Each output frame is read from near its nominal input position, moved by
+/// up to `TOLERANCE` samples to where the input looks most like the natural
+/// continuation of the previous frame, so the overlaps add up in phase.
+/// Like `resample` this is meant for loading voices, not for the audio thread.
+pub fn time_stretch(samples: &[f32], factor: f32) -> Vec<f32> {
+    let output_len = (samples.len() as f64 * factor as f64).round() as usize;
+    if (factor - 1.0).abs() < 1e-3 || samples.len() < FRAME {
+        // Nothing to stretch, or too short to overlap
+        return resample_timeline(samples, output_len);
+    }
+
+    let window: Vec<f32> = (0..FRAME)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / FRAME as f32).cos())
+        .collect();
+    let analysis_hop = HOP as f64 / factor as f64;
+    let mut output = vec![0.0; output_len + FRAME];
+    let mut weights = vec![0.0; output_len + FRAME];
+
+    let mut previous = 0isize;
+    let mut frame_index = 0;
+    while frame_index * HOP < output_len {
+        let nominal = (frame_index as f64 * analysis_hop).round() as isize;
+        let position = if frame_index == 0 {
+            0
+        } else {
+            best_alignment(samples, previous + HOP as isize, nominal)
+        };
+
+        let start = frame_index * HOP;
+        for (i, weight) in window.iter().enumerate() {
+            output[start + i] += sample_at(samples, position + i as isize) * weight;
+            weights[start + i] += weight;
+        }
+        previous = position;
+        frame_index += 1;
+    }
+
+    output.truncate(output_len);
+    for (sample, weight) in output.iter_mut().zip(weights) {
+        if weight > 1e-3 {
+            *sample /= weight;
+        }
+    }
+    output
+}
+
+/// The position within `TOLERANCE` of `nominal` whose next `HOP` samples
+/// correlate best with the ones at `continuation`.
+fn best_alignment(samples: &[f32], continuation: isize, nominal: isize) -> isize {
+    let mut best = nominal;
+    let mut best_score = f32::MIN;
+    for offset in -TOLERANCE..=TOLERANCE {
+        let candidate = nominal + offset;
+        if candidate < 0 || candidate >= samples.len() as isize {
+            continue;
+        }
+        let score: f32 = (0..HOP)
+            .step_by(CORRELATION_STRIDE)
+            .map(|i| {
+                sample_at(samples, candidate + i as isize)
+                    * sample_at(samples, continuation + i as isize)
+            })
+            .sum();
+        if score > best_score {
+            best_score = score;
+            best = candidate;
+        }
+    }
+    best
+}
+
+fn sample_at(samples: &[f32], index: isize) -> f32 {
+    if index < 0 {
+        return 0.0;
+    }
+    samples.get(index as usize).copied().unwrap_or(0.0)
+}
+
+fn resample_timeline(samples: &[f32], output_len: usize) -> Vec<f32> {
+    if output_len == samples.len() || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let step = samples.len() as f64 / output_len as f64;
+    (0..output_len)
+        .map(|i| samples[((i as f64 * step) as usize).min(samples.len() - 1)])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::constants::SAMPLE_RATE;
+    use crate::player::test_signals::{frequency, sine};
+    use test_log::test;
+
+    #[test]
+    fn test_stretch_changes_duration_and_keeps_pitch() {
+        let samples = sine(220.0, SAMPLE_RATE as usize);
+        for factor in [0.5, 0.75, 1.5, 2.0] {
+            let stretched = time_stretch(&samples, factor);
+            assert_eq!(
+                stretched.len(),
+                (SAMPLE_RATE as f32 * factor).round() as usize
+            );
+            let measured = frequency(&stretched);
+            assert!(
+                (measured - 220.0).abs() < 5.0,
+                "factor {} measured {} Hz",
+                factor,
+                measured
+            );
+        }
+    }
+
+    #[test]
+    fn test_stretch_keeps_level() {
+        let samples = sine(220.0, SAMPLE_RATE as usize);
+        let stretched = time_stretch(&samples, 1.5);
+        let peak = stretched[FRAME..stretched.len() - FRAME]
+            .iter()
+            .fold(0.0f32, |max, s| max.max(s.abs()));
+        assert!((0.9..1.05).contains(&peak), "peak {}", peak);
+    }
+
+    #[test]
+    fn test_short_and_unchanged_input() {
+        let samples = sine(220.0, 1000);
+        assert_eq!(time_stretch(&samples, 1.0), samples);
+        assert_eq!(time_stretch(&samples, 2.0).len(), 2000);
+        assert!(time_stretch(&[], 2.0).is_empty());
+    }
+}
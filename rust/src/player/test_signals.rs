@@ -0,0 +1,22 @@
+// Signals and measurements shared by the DSP tests
+
+use std::f32::consts::PI;
+
+use crate::player::constants::SAMPLE_RATE;
+
+/// A full scale sine at `SAMPLE_RATE`.
+pub fn sine(frequency: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| (2.0 * PI * frequency * i as f32 / SAMPLE_RATE as f32).sin())
+        .collect()
+}
+
+/// Frequency from the rising zero crossings, away from the edges.
+pub fn frequency(samples: &[f32]) -> f32 {
+    let middle = &samples[samples.len() / 8..samples.len() * 7 / 8];
+    let crossings = middle
+        .windows(2)
+        .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+        .count();
+    crossings as f32 * SAMPLE_RATE as f32 / middle.len() as f32
+}
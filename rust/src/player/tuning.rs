@@ -0,0 +1,244 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::api::degrees::{Degree, CENTS_PER_OCTAVE};
+use crate::api::tuning::TuningSystem;
+use crate::player::atomic::AtomicF32;
+
+pub const STANDARD_PITCH: f32 = 440.0;
+const MIN_REFERENCE_PITCH: f32 = 380.0;
+const MAX_REFERENCE_PITCH: f32 = 500.0;
+
+// 5-limit ratios above the drone, indexed by semitones
+const JUST_RATIOS: [(u32, u32); 12] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+];
+
+// Stacked pure fifths, indexed by semitones
+const PYTHAGOREAN_RATIOS: [(u32, u32); 12] = [
+    (1, 1),
+    (256, 243),
+    (9, 8),
+    (32, 27),
+    (81, 64),
+    (4, 3),
+    (729, 512),
+    (3, 2),
+    (128, 81),
+    (27, 16),
+    (16, 9),
+    (243, 128),
+];
+
+static SYSTEM: AtomicU8 = AtomicU8::new(0);
+static REFERENCE_PITCH: AtomicF32 = AtomicF32::new(STANDARD_PITCH);
+
+pub fn set_tuning(system: TuningSystem, reference_pitch: f32) -> Result<(), String> {
+    if !(MIN_REFERENCE_PITCH..=MAX_REFERENCE_PITCH).contains(&reference_pitch) {
+        return Err(format!(
+            "Reference pitch must be between {} and {} Hz, got {}",
+            MIN_REFERENCE_PITCH, MAX_REFERENCE_PITCH, reference_pitch
+        ));
+    }
+    SYSTEM.store(system as u8, Ordering::Relaxed);
+    REFERENCE_PITCH.store(reference_pitch);
+    Ok(())
+}
+
+/// The tuning selected through the API, read without locking.
+pub fn current() -> Tuning {
+    let system = match SYSTEM.load(Ordering::Relaxed) {
+        1 => TuningSystem::JustIntonation,
+        2 => TuningSystem::Pythagorean,
+        _ => TuningSystem::EqualTemperament,
+    };
+    Tuning {
+        system,
+        reference_pitch: REFERENCE_PITCH.load(),
+    }
+}
+
+/// Maps equal tempered frequencies at A4 = 440 Hz to the selected tuning.
+///
+/// The drone always sits on the equal tempered grid at the reference pitch,
+/// the other tuning systems only change the degrees played above it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tuning {
+    pub system: TuningSystem,
+    pub reference_pitch: f32,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning {
+            system: TuningSystem::EqualTemperament,
+            reference_pitch: STANDARD_PITCH,
+        }
+    }
+}
+
+impl Tuning {
+    /// Frequency ratio of `degree` above the drone, within one octave.
+    ///
+    /// Microtonal degrees are defined by their cents and play as such in
+    /// every tuning system.
+    pub fn ratio(&self, degree: Degree) -> f32 {
+        let (numerator, denominator) = match (self.system, degree.note()) {
+            (TuningSystem::JustIntonation, Some(note)) => JUST_RATIOS[note.semitones() as usize],
+            (TuningSystem::Pythagorean, Some(note)) => {
+                PYTHAGOREAN_RATIOS[note.semitones() as usize]
+            }
+            _ => return equal_tempered_ratio(degree),
+        };
+        numerator as f32 / denominator as f32
+    }
+
+    pub fn root_frequency(&self, equal_tempered: f32) -> f32 {
+        equal_tempered * self.reference_pitch / STANDARD_PITCH
+    }
+
+    /// Tunes `degree` against the `drone`, keeping the octave the equal
+    /// tempered frequency would be played in.
+    pub fn relative_frequency(&self, drone: f32, equal_tempered: f32, degree: Degree) -> f32 {
+        let equal_tempered = self.root_frequency(equal_tempered);
+        if self.system == TuningSystem::EqualTemperament {
+            return equal_tempered;
+        }
+
+        let octaves = (equal_tempered / (drone * equal_tempered_ratio(degree)))
+            .log2()
+            .round();
+        drone * self.ratio(degree) * 2.0_f32.powf(octaves)
+    }
+}
+
+fn equal_tempered_ratio(degree: Degree) -> f32 {
+    2.0_f32.powf(degree.cents() as f32 / CENTS_PER_OCTAVE as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::notes::{Note, ALL_NOTES};
+    use test_log::test;
+
+    fn tuning(system: TuningSystem, reference_pitch: f32) -> Tuning {
+        Tuning {
+            system,
+            reference_pitch,
+        }
+    }
+
+    // Equal tempered frequency `semitones` above `drone` plus `octaves`
+    fn equal_tempered(drone: f32, degree: Note, octaves: i32) -> f32 {
+        drone * 2.0_f32.powf((degree.semitones() as i32 + 12 * octaves) as f32 / 12.0)
+    }
+
+    #[test]
+    fn test_equal_temperament_is_unchanged_at_440() {
+        let tuning = Tuning::default();
+        assert_eq!(65.40639, tuning.root_frequency(65.40639));
+        assert_eq!(
+            329.62756,
+            tuning.relative_frequency(65.40639, 329.62756, Note::Three.into())
+        );
+    }
+
+    #[test]
+    fn test_reference_pitch_scales_everything() {
+        let tuning = tuning(TuningSystem::EqualTemperament, 432.0);
+        assert!((tuning.root_frequency(110.0) - 108.0).abs() < 1e-4);
+        assert!((tuning.relative_frequency(108.0, 440.0, Note::One.into()) - 432.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_just_intonation_ratios_against_drone() {
+        let tuning = tuning(TuningSystem::JustIntonation, 440.0);
+        let drone = 73.4162;
+        for (degree, (numerator, denominator)) in ALL_NOTES.into_iter().zip(JUST_RATIOS) {
+            for octaves in [1, 2] {
+                let frequency = tuning.relative_frequency(
+                    drone,
+                    equal_tempered(drone, degree, octaves),
+                    degree.into(),
+                );
+                let expected = numerator as f32 / denominator as f32 * 2.0_f32.powi(octaves);
+                assert!(
+                    (frequency / drone - expected).abs() < 1e-4,
+                    "{} is {} times the drone, expected {}",
+                    degree,
+                    frequency / drone,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_just_intonation_thirds_and_fifths_are_pure() {
+        let tuning = tuning(TuningSystem::JustIntonation, 442.0);
+        let drone = 98.0;
+        let third = tuning.relative_frequency(
+            drone,
+            equal_tempered(drone, Note::Three, 2),
+            Note::Three.into(),
+        );
+        let fifth = tuning.relative_frequency(
+            drone,
+            equal_tempered(drone, Note::Five, 2),
+            Note::Five.into(),
+        );
+        assert!((third / drone - 5.0).abs() < 1e-4);
+        assert!((fifth / drone - 6.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_pythagorean_ratios_against_drone() {
+        let tuning = tuning(TuningSystem::Pythagorean, 415.0);
+        let drone = 61.7354;
+        for (degree, (numerator, denominator)) in ALL_NOTES.into_iter().zip(PYTHAGOREAN_RATIOS) {
+            let frequency =
+                tuning.relative_frequency(drone, equal_tempered(drone, degree, 2), degree.into());
+            let expected = numerator as f32 / denominator as f32 * 4.0;
+            assert!(
+                (frequency / drone - expected).abs() < 1e-4,
+                "{} is {} times the drone, expected {}",
+                degree,
+                frequency / drone,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_microtonal_degrees_keep_their_cents() {
+        let drone = 65.40639;
+        let neutral_third = Degree::new(350).unwrap();
+        let equal_tempered = drone * 4.0 * 2.0_f32.powf(3.5 / 12.0);
+        for system in [
+            TuningSystem::EqualTemperament,
+            TuningSystem::JustIntonation,
+            TuningSystem::Pythagorean,
+        ] {
+            let frequency =
+                tuning(system, 440.0).relative_frequency(drone, equal_tempered, neutral_third);
+            assert!((frequency - equal_tempered).abs() < 1e-3, "{:?}", system);
+        }
+    }
+
+    #[test]
+    fn test_set_tuning_rejects_unreasonable_reference() {
+        assert!(set_tuning(TuningSystem::JustIntonation, 100.0).is_err());
+        assert!(set_tuning(TuningSystem::JustIntonation, f32::NAN).is_err());
+    }
+}
@@ -1,55 +1,280 @@
-use ctor::ctor;
-use hound::WavReader;
+use hound::{SampleFormat, WavReader};
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+use rodio::{Decoder, Source};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::Cursor;
-use std::path::Path;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
+use crate::api::notes::{Note, ALL_NOTES};
+use crate::player::constants::SAMPLE_RATE;
+use crate::player::playback::{voice_style, PlaybackCursor, VoiceStyle};
+use crate::player::resample::resample;
+use crate::player::speech;
+
+/// Container of a voice file, detected from its first bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AudioFormat {
+    Wav,
+    Flac,
+    Vorbis,
+    Mp3,
+}
+
+pub fn detect_format(data: &[u8]) -> Option<AudioFormat> {
+    match data {
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some(AudioFormat::Wav),
+        [b'f', b'L', b'a', b'C', ..] => Some(AudioFormat::Flac),
+        [b'O', b'g', b'g', b'S', ..] => Some(AudioFormat::Vorbis),
+        // Either an ID3 tag or straight into an MPEG audio frame sync
+        [b'I', b'D', b'3', ..] => Some(AudioFormat::Mp3),
+        [0xFF, second, ..] if second & 0xE0 == 0xE0 => Some(AudioFormat::Mp3),
+        _ => None,
+    }
+}
+
+/// Decoded voice samples, mono at `SAMPLE_RATE`.
+///
+/// The samples are decoded once and shared, so cloning a `WavFile` only bumps
+/// a reference count and is safe to do on the audio thread. Playback goes
+/// through a `PlaybackCursor`.
 #[derive(Clone)]
 pub struct WavFile {
-    current_sample: usize,
-    samples: Vec<f32>,
+    samples: Arc<[f32]>,
 }
 
 impl WavFile {
+    /// Decodes a WAV, FLAC, Ogg Vorbis or MP3 voice file.
+    ///
+    /// Channels are averaged into mono and the result is resampled to
+    /// `SAMPLE_RATE`, so voices play at the right speed whatever they were
+    /// recorded at.
     pub fn new(data: &[u8]) -> Result<Self, String> {
+        match detect_format(data) {
+            Some(AudioFormat::Wav) => Self::from_wav(data),
+            Some(format) => Self::from_compressed(data, format),
+            None => Err("Failed to open voice file: unknown format".to_string()),
+        }
+    }
+
+    /// Decodes 8, 16, 24 or 32-bit integer and 32-bit float WAV data.
+    pub fn from_wav(data: &[u8]) -> Result<Self, String> {
         let cursor = Cursor::new(data);
         let mut reader =
             WavReader::new(cursor).map_err(|e| format!("Failed to open WAV file: {}", e))?;
+        let spec = reader.spec();
+
+        let interleaved: Vec<f32> = match (spec.sample_format, spec.bits_per_sample) {
+            (SampleFormat::Float, 32) => reader.samples::<f32>().collect::<Result<_, _>>(),
+            (SampleFormat::Int, bits @ 8..=32) => {
+                // Full scale is the largest positive value, as for 16-bit before
+                let scale = ((1i64 << (bits - 1)) - 1) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|s| s.map(|x| x as f32 / scale))
+                    .collect::<Result<_, _>>()
+            }
+            (format, bits) => {
+                return Err(format!("Unsupported WAV format: {}-bit {:?}", bits, format))
+            }
+        }
+        .map_err(|e| format!("Failed to read WAV samples: {}", e))?;
 
-        let samples: Vec<f32> = reader
-            .samples::<i16>()
-            .map(|s| s.map(|x| x as f32 / i16::MAX as f32))
-            .collect::<Result<Vec<f32>, _>>()
-            .map_err(|e| format!("Failed to read WAV samples: {}", e))?;
+        let mono = downmix(&interleaved, spec.channels);
+        Ok(Self::from_samples(resample(
+            &mono,
+            spec.sample_rate,
+            SAMPLE_RATE,
+        )))
+    }
 
-        Ok(WavFile {
-            current_sample: 0,
-            samples,
-        })
+    fn from_compressed(data: &[u8], format: AudioFormat) -> Result<Self, String> {
+        let cursor = Cursor::new(data.to_vec());
+        let decoder = match format {
+            AudioFormat::Flac => Decoder::new_flac(cursor),
+            AudioFormat::Vorbis => Decoder::new_vorbis(cursor),
+            AudioFormat::Mp3 => Decoder::new_mp3(cursor),
+            AudioFormat::Wav => Decoder::new_wav(cursor),
+        }
+        .map_err(|e| format!("Failed to open {:?} file: {}", format, e))?;
+
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+        let interleaved: Vec<f32> = decoder.map(|s| s as f32 / i16::MAX as f32).collect();
+        if interleaved.is_empty() {
+            return Err(format!("Failed to read {:?} samples: no audio", format));
+        }
+
+        let mono = downmix(&interleaved, channels);
+        Ok(Self::from_samples(resample(
+            &mono,
+            sample_rate,
+            SAMPLE_RATE,
+        )))
     }
 
-    pub fn get_next_sample(&mut self) -> Option<f32> {
-        if self.current_sample >= self.samples.len() {
-            None // End of file reached
-        } else {
-            let sample = self.samples[self.current_sample];
-            self.current_sample += 1;
-            Some(sample)
+    /// Mono samples already at `SAMPLE_RATE`.
+    pub fn from_samples(samples: Vec<f32>) -> Self {
+        WavFile {
+            samples: samples.into(),
         }
     }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn samples(&self) -> &Arc<[f32]> {
+        &self.samples
+    }
+
+    /// A new cursor at the start, sharing the samples.
+    pub fn cursor(&self) -> PlaybackCursor {
+        PlaybackCursor::new(self.samples.clone())
+    }
+}
+
+fn downmix(interleaved: &[f32], channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return interleaved.to_vec();
+    }
+    interleaved
+        .chunks_exact(channels as usize)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+pub const DEFAULT_VOICE_PACK: &str = "default";
+pub const MANIFEST_FILE: &str = "manifest.txt";
+
+// Looked up in this order when a directory has no manifest
+const VOICE_EXTENSIONS: [&str; 4] = ["wav", "flac", "ogg", "mp3"];
+
+/// Named sets of voices, keyed like `Note::to_keyboard_note`.
+///
+/// The files loaded through `init_wav_files_from_bytes`, `init_voices_from_dir`
+/// and `load_wav_files` go to the default pack. The active pack is the one new
+/// exercises are answered with, degrees it has no file for are spoken by the
+/// speech synthesizer.
+pub struct VoicePacks {
+    packs: BTreeMap<String, HashMap<i32, WavFile>>,
+    active: String,
+}
+
+impl VoicePacks {
+    pub fn new() -> Self {
+        VoicePacks {
+            packs: BTreeMap::from([(DEFAULT_VOICE_PACK.to_string(), HashMap::new())]),
+            active: DEFAULT_VOICE_PACK.to_string(),
+        }
+    }
+
+    /// Adds a pack, replacing any pack with the same name.
+    pub fn insert(&mut self, name: &str, voices: HashMap<i32, WavFile>) {
+        self.packs.insert(name.to_string(), voices);
+    }
+
+    fn default_pack(&mut self) -> &mut HashMap<i32, WavFile> {
+        self.packs
+            .entry(DEFAULT_VOICE_PACK.to_string())
+            .or_default()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.packs.keys().cloned().collect()
+    }
+
+    pub fn active(&self) -> &str {
+        &self.active
+    }
+
+    pub fn set_active(&mut self, name: &str) -> Result<(), String> {
+        if !self.packs.contains_key(name) {
+            return Err(format!("Unknown voice pack: {}", name));
+        }
+        self.active = name.to_string();
+        Ok(())
+    }
+
+    /// Every degree has a voice in the snapshot, recorded or synthesized.
+    pub fn voice_bank(&self) -> VoiceBank {
+        let mut voices = self.packs.get(&self.active).cloned().unwrap_or_default();
+        for (key, voice) in speech::spoken_degrees() {
+            voices.entry(*key).or_insert_with(|| voice.clone());
+        }
+        VoiceBank {
+            voices,
+            style: VoiceStyle::default(),
+        }
+    }
+}
+
+impl Default for VoicePacks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "embedded-assets")]
+const EMBEDDED_VOICES: [&[u8]; 12] = [
+    include_bytes!("../../resources/1.wav"),
+    include_bytes!("../../resources/2.wav"),
+    include_bytes!("../../resources/3.wav"),
+    include_bytes!("../../resources/4.wav"),
+    include_bytes!("../../resources/5.wav"),
+    include_bytes!("../../resources/6.wav"),
+    include_bytes!("../../resources/7.wav"),
+    include_bytes!("../../resources/8.wav"),
+    include_bytes!("../../resources/9.wav"),
+    include_bytes!("../../resources/10.wav"),
+    include_bytes!("../../resources/11.wav"),
+    include_bytes!("../../resources/12.wav"),
+];
+
+/// The default voices compiled in with the `embedded-assets` feature, empty
+/// without it.
+pub fn embedded_voices() -> HashMap<i32, WavFile> {
+    #[cfg(feature = "embedded-assets")]
+    {
+        ALL_NOTES
+            .iter()
+            .zip(EMBEDDED_VOICES)
+            .map(|(note, data)| {
+                let voice = WavFile::new(data).expect("embedded voices are valid WAV files");
+                (note.to_keyboard_note(), voice)
+            })
+            .collect()
+    }
+    #[cfg(not(feature = "embedded-assets"))]
+    {
+        HashMap::new()
+    }
 }
 
-// Lazy static map to store WavFile structs
 lazy_static! {
-    static ref WAV_FILES: Mutex<HashMap<i32, WavFile>> = Mutex::new(HashMap::new());
+    // Decoded on first use, before any player starts
+    static ref VOICE_PACKS: Mutex<VoicePacks> = {
+        let mut packs = VoicePacks::new();
+        packs.insert(DEFAULT_VOICE_PACK, embedded_voices());
+        Mutex::new(packs)
+    };
+}
+
+fn voice_packs() -> Result<std::sync::MutexGuard<'static, VoicePacks>, String> {
+    VOICE_PACKS
+        .lock()
+        .map_err(|_| "Failed to lock VOICE_PACKS".to_string())
 }
 
 /// Function to load and initialize the WAV files, called from Dart
 pub fn load_wav_files(data_map: HashMap<i32, Vec<u8>>) -> Result<(), String> {
-    let mut wav_files = WAV_FILES.lock().map_err(|_| "Failed to lock WAV_FILES")?;
+    let mut packs = voice_packs()?;
+    let wav_files = packs.default_pack();
 
     for (key, data) in data_map {
         let wav_file = WavFile::new(&data)?;
@@ -59,55 +284,581 @@ pub fn load_wav_files(data_map: HashMap<i32, Vec<u8>>) -> Result<(), String> {
     Ok(())
 }
 
-/// Function to retrieve a WavFile by its integer key
-pub fn get_wav_file(key: i32) -> Result<WavFile, String> {
-    let wav_files = WAV_FILES.lock().map_err(|_| "Failed to lock WAV_FILES")?;
+/// Parses a voice pack manifest, one `degree: file` per line.
+///
+/// Degrees are written as in `--notes`, e.g. `b3: me.ogg` or `mi: mi.wav`.
+/// Blank lines and comments starting with `# ` are skipped, the space keeps
+/// `#4: fi.wav` a degree.
+pub fn parse_manifest(manifest: &str) -> Result<Vec<(Note, String)>, String> {
+    let mut entries: Vec<(Note, String)> = Vec::new();
+    for line in manifest.lines().map(str::trim) {
+        if line.is_empty() || line == "#" || line.starts_with("# ") {
+            continue;
+        }
+        let (degree, file) = line
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid manifest line: {}", line))?;
+        let note: Note = degree.trim().parse()?;
+        if entries.iter().any(|(existing, _)| *existing == note) {
+            return Err(format!("Degree {} appears twice in the manifest", note));
+        }
+        entries.push((note, file.trim().to_string()));
+    }
+    Ok(entries)
+}
 
-    wav_files
-        .get(&key)
-        .cloned() // Return a clone of the WavFile
-        .ok_or_else(|| format!("No WAV file found for key: {}", key))
+fn decode_pack(
+    entries: Vec<(Note, String)>,
+    mut read: impl FnMut(&str) -> Result<Vec<u8>, String>,
+) -> Result<HashMap<i32, WavFile>, String> {
+    entries
+        .into_iter()
+        .map(|(note, file)| {
+            let voice = WavFile::new(&read(&file)?).map_err(|e| format!("{}: {}", file, e))?;
+            Ok((note.to_keyboard_note(), voice))
+        })
+        .collect()
 }
 
-#[ctor]
-fn global_init() {
-    init_wav_files();
+fn find_voice_file(dir: &Path, key: i32) -> Option<PathBuf> {
+    VOICE_EXTENSIONS
+        .iter()
+        .map(|extension| dir.join(format!("{}.{}", key, extension)))
+        .find(|path| path.exists())
 }
 
-pub fn init_wav_files_from_bytes(wav_data: Vec<Vec<u8>>) {
-    let mut wav_files = WAV_FILES.lock().unwrap();
+// Decodes the voices in `dir`, without touching `VOICE_PACKS`
+fn read_voice_pack_dir(dir: &Path) -> Result<HashMap<i32, WavFile>, String> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let entries = if manifest_path.exists() {
+        let manifest = fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?;
+        parse_manifest(&manifest)?
+    } else {
+        ALL_NOTES
+            .iter()
+            .filter_map(|note| {
+                let path = find_voice_file(dir, note.to_keyboard_note())?;
+                Some((*note, path.file_name()?.to_string_lossy().into_owned()))
+            })
+            .collect()
+    };
+    if entries.is_empty() {
+        return Err(format!("No voices found in {}", dir.display()));
+    }
 
-    for (key, data) in wav_data.iter().enumerate() {
-        let wav_file = WavFile::new(data);
-        if let Ok(file) = wav_file {
-            wav_files.insert((key + 1) as i32, file);
-        } else {
-            eprintln!("Error creating WavFile from data for key: {}", key + 1);
+    decode_pack(entries, |file| {
+        let path = dir.join(file);
+        fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    })
+}
+
+/// Loads a pack from `dir`, using its manifest when there is one and the
+/// `1.wav` .. `12.wav` naming of `resources` otherwise.
+pub fn load_voice_pack_from_dir(name: &str, dir: &Path) -> Result<(), String> {
+    let voices = read_voice_pack_dir(dir)?;
+    voice_packs()?.insert(name, voices);
+    Ok(())
+}
+
+/// Loads a pack from a manifest and the bytes of the files it names.
+pub fn load_voice_pack_from_bundle(
+    name: &str,
+    manifest: &str,
+    files: &HashMap<String, Vec<u8>>,
+) -> Result<(), String> {
+    let voices = decode_pack(parse_manifest(manifest)?, |file| {
+        files
+            .get(file)
+            .cloned()
+            .ok_or_else(|| format!("{} is missing from the bundle", file))
+    })?;
+    voice_packs()?.insert(name, voices);
+    Ok(())
+}
+
+/// Selects the pack used from the next `start_playing` on.
+pub fn set_active_voice_pack(name: &str) -> Result<(), String> {
+    voice_packs()?.set_active(name)
+}
+
+pub fn active_voice_pack() -> Result<String, String> {
+    Ok(voice_packs()?.active().to_string())
+}
+
+pub fn voice_pack_names() -> Result<Vec<String>, String> {
+    Ok(voice_packs()?.names())
+}
+
+/// Snapshot of the active voice pack, looked up without locking.
+///
+/// Taken before playback starts so the audio thread never touches `VOICE_PACKS`.
+#[derive(Clone, Default)]
+pub struct VoiceBank {
+    voices: HashMap<i32, WavFile>,
+    style: VoiceStyle,
+}
+
+impl VoiceBank {
+    /// The voice as prepared for the bank's `VoiceStyle`.
+    pub fn get(&self, key: i32) -> Option<WavFile> {
+        self.voices.get(&key).cloned()
+    }
+
+    /// A cursor playing the voice with the bank's `VoiceStyle`.
+    pub fn cursor(&self, key: i32) -> Option<PlaybackCursor> {
+        let mut cursor = self.voices.get(&key)?.cursor();
+        cursor.set_rate(self.style.rate()).ok()?;
+        Some(cursor)
+    }
+
+    /// How long the voice plays for, in seconds.
+    pub fn duration(&self, key: i32) -> Option<f32> {
+        let len = self.voices.get(&key)?.len();
+        Some(len as f32 / self.style.rate() / SAMPLE_RATE as f32)
+    }
+
+    /// Time-stretches every voice for `style`, which takes a while for long
+    /// recordings.
+    pub fn with_style(self, style: VoiceStyle) -> VoiceBank {
+        if style == VoiceStyle::default() {
+            return VoiceBank { style, ..self };
         }
+        let voices = self
+            .voices
+            .into_iter()
+            .map(|(key, voice)| (key, WavFile::from_samples(style.prepare(voice.samples()))))
+            .collect();
+        VoiceBank { voices, style }
     }
 }
 
-pub fn init_wav_files() {
-    let mut wav_files = WAV_FILES.lock().unwrap();
+/// The active voice pack, prepared for the voice style set through the API.
+pub fn voice_bank() -> Result<VoiceBank, String> {
+    // The first use synthesizes every degree, which shouldn't hold the lock.
+    // Afterwards filling in the missing degrees only shares their samples.
+    speech::spoken_degrees();
+    let bank = voice_packs()?.voice_bank();
+    Ok(bank.with_style(voice_style()))
+}
 
-    for key in 1..=12 {
-        let path = format!("{}/resources/{}.wav", env!("CARGO_MANIFEST_DIR"), key);
+/// Loads the default voices from a directory, like `load_voice_pack_from_dir`.
+pub fn init_voices_from_dir(dir: &Path) -> Result<(), String> {
+    load_voice_pack_from_dir(DEFAULT_VOICE_PACK, dir)
+}
 
-        // Check if the file exists
-        if Path::new(&path).exists() {
-            // Read the file as bytes
-            match fs::read(&path) {
-                Ok(data) => {
-                    // Create the WavFile from the byte data
-                    let wav_file = WavFile::new(&data);
-                    wav_files.insert(key, wav_file.unwrap());
-                }
-                Err(e) => {
-                    eprintln!("Error reading WAV file at path {}: {}", path, e);
+/// Loads the default voices from file contents in degree order, 1, b2, 2 ...
+///
+/// Nothing is replaced unless every file decodes.
+pub fn init_voices_from_bytes(wav_data: &[Vec<u8>]) -> Result<(), String> {
+    if wav_data.is_empty() || wav_data.len() > ALL_NOTES.len() {
+        return Err(format!(
+            "Expected 1 to {} voice files, got {}",
+            ALL_NOTES.len(),
+            wav_data.len()
+        ));
+    }
+    let voices = ALL_NOTES
+        .iter()
+        .zip(wav_data)
+        .map(|(note, data)| {
+            let voice = WavFile::new(data).map_err(|e| format!("Voice for {}: {}", note, e))?;
+            Ok((note.to_keyboard_note(), voice))
+        })
+        .collect::<Result<_, String>>()?;
+    voice_packs()?.insert(DEFAULT_VOICE_PACK, voices);
+    Ok(())
+}
+
+/// Same as `init_voices_from_bytes`, reporting errors on stderr.
+pub fn init_wav_files_from_bytes(wav_data: Vec<Vec<u8>>) {
+    if let Err(e) = init_voices_from_bytes(&wav_data) {
+        eprintln!("Error loading voices: {}", e);
+    }
+}
+
+/// Whether the active pack has recorded voices, rather than only spoken ones.
+pub fn voices_loaded() -> bool {
+    voice_packs()
+        .map(|packs| {
+            packs
+                .packs
+                .get(&packs.active)
+                .is_some_and(|v| !v.is_empty())
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::pitch::{detect_pitches, median_pitch};
+    use hound::{WavSpec, WavWriter};
+    use std::f32::consts::PI;
+    use test_log::test;
+
+    const FIXTURE_SECONDS: f32 = 0.1;
+
+    // A 440 Hz sine at half scale, the same in every channel
+    fn fixture(spec: WavSpec) -> Vec<u8> {
+        let mut data = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut data, spec).unwrap();
+        let frames = (spec.sample_rate as f32 * FIXTURE_SECONDS) as usize;
+        for i in 0..frames {
+            let value = 0.5 * (2.0 * PI * 440.0 * i as f32 / spec.sample_rate as f32).sin();
+            for _ in 0..spec.channels {
+                match spec.sample_format {
+                    SampleFormat::Float => writer.write_sample(value).unwrap(),
+                    SampleFormat::Int => {
+                        let scale = ((1i64 << (spec.bits_per_sample - 1)) - 1) as f32;
+                        writer.write_sample((value * scale).round() as i32).unwrap()
+                    }
                 }
             }
-        } else {
-            eprintln!("Warning: WAV file not found at path: {}", path);
+        }
+        writer.finalize().unwrap();
+        data.into_inner()
+    }
+
+    fn spec(channels: u16, sample_rate: u32, bits: u16, format: SampleFormat) -> WavSpec {
+        WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: bits,
+            sample_format: format,
+        }
+    }
+
+    fn assert_is_fixture_sine(wav: &WavFile, tolerance: f32) {
+        let expected_len = (SAMPLE_RATE as f32 * FIXTURE_SECONDS) as usize;
+        assert!(
+            wav.len().abs_diff(expected_len) <= 1,
+            "{} samples",
+            wav.len()
+        );
+
+        // Skip the edges, where the resampler kernel is cut short
+        for i in 100..wav.len() - 100 {
+            let expected = 0.5 * (2.0 * PI * 440.0 * i as f32 / SAMPLE_RATE as f32).sin();
+            assert!(
+                (wav.samples[i] - expected).abs() < tolerance,
+                "sample {} is {}, expected {}",
+                i,
+                wav.samples[i],
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_reads_integer_and_float_formats() {
+        for (bits, format, tolerance) in [
+            (8, SampleFormat::Int, 2e-2),
+            (16, SampleFormat::Int, 1e-4),
+            (24, SampleFormat::Int, 1e-5),
+            (32, SampleFormat::Int, 1e-5),
+            (32, SampleFormat::Float, 1e-6),
+        ] {
+            let wav = WavFile::new(&fixture(spec(1, SAMPLE_RATE, bits, format))).unwrap();
+            assert_is_fixture_sine(&wav, tolerance);
+        }
+    }
+
+    #[test]
+    fn test_downmixes_channels() {
+        for channels in [2, 6] {
+            let wav =
+                WavFile::new(&fixture(spec(channels, SAMPLE_RATE, 16, SampleFormat::Int))).unwrap();
+            assert_is_fixture_sine(&wav, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_downmix_averages_channels() {
+        assert_eq!(downmix(&[1.0, 0.0, 0.5, 0.5], 2), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_resamples_to_engine_rate() {
+        for sample_rate in [22050, 44100, 96000] {
+            let wav = WavFile::new(&fixture(spec(2, sample_rate, 24, SampleFormat::Int))).unwrap();
+            assert_is_fixture_sine(&wav, 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        assert!(WavFile::new(b"not a wav file").is_err());
+        assert!(WavFile::new(b"OggS but not really vorbis").is_err());
+        assert!(WavFile::new(b"fLaC but not really flac").is_err());
+    }
+
+    // Minimal FLAC encoder storing 16-bit samples verbatim, enough to check
+    // the decoder end to end without an encoder dependency
+    fn flac_fixture(channels: u16, sample_rate: u32) -> Vec<u8> {
+        const BLOCK_SIZE: usize = 4096;
+        let frames = (sample_rate as f32 * FIXTURE_SECONDS) as usize;
+        let samples: Vec<i16> = (0..frames)
+            .map(|i| {
+                let value = 0.5 * (2.0 * PI * 440.0 * i as f32 / sample_rate as f32).sin();
+                (value * i16::MAX as f32).round() as i16
+            })
+            .collect();
+
+        let mut data = b"fLaC".to_vec();
+        // Last metadata block, STREAMINFO, 34 bytes
+        data.extend([0x80, 0, 0, 34]);
+        data.extend((BLOCK_SIZE as u16).to_be_bytes());
+        data.extend((BLOCK_SIZE as u16).to_be_bytes());
+        data.extend([0; 6]); // Frame sizes unknown
+        let packed =
+            (sample_rate as u64) << 44 | ((channels - 1) as u64) << 41 | 15 << 36 | frames as u64;
+        data.extend(packed.to_be_bytes());
+        data.extend([0; 16]); // No MD5
+
+        for (number, block) in samples.chunks(BLOCK_SIZE).enumerate() {
+            let start = data.len();
+            data.extend([0xFF, 0xF8]); // Sync, fixed block size
+            data.push(0x70); // Block size in 16 bits at the end, rate from STREAMINFO
+            data.push(((channels - 1) as u8) << 4 | 0x08); // Independent channels, 16-bit
+            data.push(number as u8); // Frame number, UTF-8 coded below 128
+            data.extend(((block.len() - 1) as u16).to_be_bytes());
+            let crc = crc8(&data[start..]);
+            data.push(crc);
+            // VERBATIM subframe, the same for every channel
+            let subframe: Vec<u8> = std::iter::once(0x02)
+                .chain(block.iter().flat_map(|sample| sample.to_be_bytes()))
+                .collect();
+            for _ in 0..channels {
+                data.extend(&subframe);
+            }
+            let crc = crc16(&data[start..]);
+            data.extend(crc.to_be_bytes());
+        }
+        data
+    }
+
+    fn crc8(data: &[u8]) -> u8 {
+        data.iter().fold(0u8, |mut crc, byte| {
+            crc ^= byte;
+            for _ in 0..8 {
+                crc = if crc & 0x80 != 0 {
+                    crc << 1 ^ 0x07
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        })
+    }
+
+    fn crc16(data: &[u8]) -> u16 {
+        data.iter().fold(0u16, |mut crc, &byte| {
+            crc ^= (byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    crc << 1 ^ 0x8005
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        })
+    }
+
+    #[test]
+    fn test_detects_format_from_header() {
+        assert_eq!(
+            detect_format(&fixture(spec(1, SAMPLE_RATE, 16, SampleFormat::Int))),
+            Some(AudioFormat::Wav)
+        );
+        assert_eq!(
+            detect_format(&flac_fixture(1, SAMPLE_RATE)),
+            Some(AudioFormat::Flac)
+        );
+        assert_eq!(detect_format(b"OggS\0\x02"), Some(AudioFormat::Vorbis));
+        assert_eq!(detect_format(b"ID3\x04\0"), Some(AudioFormat::Mp3));
+        assert_eq!(
+            detect_format(&[0xFF, 0xFB, 0x90, 0x00]),
+            Some(AudioFormat::Mp3)
+        );
+        assert_eq!(detect_format(b"RIFF\0\0\0\0AVI "), None);
+        assert_eq!(detect_format(&[]), None);
+    }
+
+    #[test]
+    fn test_decodes_flac() {
+        let wav = WavFile::new(&flac_fixture(1, SAMPLE_RATE)).unwrap();
+        assert_is_fixture_sine(&wav, 1e-4);
+
+        let wav = WavFile::new(&flac_fixture(2, 44100)).unwrap();
+        assert_is_fixture_sine(&wav, 1e-2);
+    }
+
+    // Half a second of the fixture sine, encoded by libvorbis at 48 kHz mono
+    // and by LAME at 44.1 kHz stereo
+    const VORBIS_FIXTURE: &[u8] = include_bytes!("../../tests/fixtures/sine.ogg");
+    const MP3_FIXTURE: &[u8] = include_bytes!("../../tests/fixtures/sine.mp3");
+
+    fn assert_is_encoded_sine(wav: &WavFile, expected_len: std::ops::Range<usize>) {
+        assert!(expected_len.contains(&wav.len()), "{} samples", wav.len());
+        let pitch = median_pitch(&detect_pitches(wav.samples(), SAMPLE_RATE)).unwrap();
+        assert!(
+            (pitch.frequency - 440.0).abs() < 2.0,
+            "{} Hz",
+            pitch.frequency
+        );
+        let peak = wav
+            .samples()
+            .iter()
+            .fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 0.5).abs() < 0.05, "peak {}", peak);
+    }
+
+    #[test]
+    fn test_decodes_vorbis() {
+        assert_eq!(detect_format(VORBIS_FIXTURE), Some(AudioFormat::Vorbis));
+        let wav = WavFile::new(VORBIS_FIXTURE).unwrap();
+        let half_second = SAMPLE_RATE as usize / 2;
+        // The decoder may keep up to one short block past the end of stream
+        assert_is_encoded_sine(&wav, half_second..half_second + 256);
+    }
+
+    #[test]
+    fn test_decodes_mp3() {
+        assert_eq!(detect_format(MP3_FIXTURE), Some(AudioFormat::Mp3));
+        let wav = WavFile::new(MP3_FIXTURE).unwrap();
+        let half_second = SAMPLE_RATE as usize / 2;
+        // Encoder delay and padding, under two frames of 1152 samples
+        let two_frames = 2 * 1152 * SAMPLE_RATE as usize / 44100;
+        assert_is_encoded_sine(&wav, half_second..half_second + two_frames);
+    }
+
+    fn voice_fixture() -> Vec<u8> {
+        fixture(spec(1, SAMPLE_RATE, 16, SampleFormat::Int))
+    }
+
+    fn full_manifest() -> String {
+        ALL_NOTES
+            .iter()
+            .map(|note| format!("{}: {}.wav\n", note, note.to_keyboard_note()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        assert_eq!(
+            parse_manifest("# Italian\n\ndo: do.wav\n b3 : mi bemolle.ogg \n#4: fa diesis.wav"),
+            Ok(vec![
+                (Note::One, "do.wav".to_string()),
+                (Note::FlatThree, "mi bemolle.ogg".to_string()),
+                (Note::SharpFour, "fa diesis.wav".to_string())
+            ])
+        );
+        assert!(parse_manifest("do.wav").is_err());
+        assert!(parse_manifest("9: nine.wav").is_err());
+        assert!(parse_manifest("1: a.wav\ndo: b.wav").is_err());
+    }
+
+    #[test]
+    fn test_load_bundle_and_select_pack() {
+        let files: HashMap<String, Vec<u8>> = (1..=12)
+            .map(|key| (format!("{}.wav", key), voice_fixture()))
+            .collect();
+        let entries = parse_manifest(&full_manifest()).unwrap();
+        let voices = decode_pack(entries, |file| Ok(files[file].clone())).unwrap();
+
+        let recorded_len = voices[&1].len();
+
+        let mut packs = VoicePacks::new();
+        packs.insert("italiano", voices);
+        assert_eq!(packs.names(), vec!["default", "italiano"]);
+        assert_eq!(packs.active(), DEFAULT_VOICE_PACK);
+        assert_ne!(packs.voice_bank().get(1).unwrap().len(), recorded_len);
+
+        packs.set_active("italiano").unwrap();
+        assert_eq!(packs.active(), "italiano");
+        assert_eq!(packs.voice_bank().get(1).unwrap().len(), recorded_len);
+        assert_eq!(packs.voice_bank().get(12).unwrap().len(), recorded_len);
+    }
+
+    #[test]
+    fn test_missing_voices_are_spoken() {
+        let mut packs = VoicePacks::new();
+        let voices = decode_pack(vec![(Note::One, "do.wav".to_string())], |_| {
+            Ok(voice_fixture())
+        })
+        .unwrap();
+        let recorded_len = voices[&1].len();
+        packs.insert("partial", voices);
+        packs.set_active("partial").unwrap();
+
+        let bank = packs.voice_bank();
+        assert_eq!(bank.get(1).unwrap().len(), recorded_len);
+        for note in ALL_NOTES {
+            assert!(bank.get(note.to_keyboard_note()).is_some(), "{}", note);
+        }
+    }
+
+    #[test]
+    fn test_unknown_pack_is_not_selected() {
+        let mut packs = VoicePacks::new();
+        assert!(packs.set_active("missing").is_err());
+        assert_eq!(packs.active(), DEFAULT_VOICE_PACK);
+    }
+
+    #[test]
+    fn test_bundle_reports_missing_files() {
+        let result = load_voice_pack_from_bundle("broken", "1: one.wav", &HashMap::new());
+        assert_eq!(
+            result.err(),
+            Some("one.wav is missing from the bundle".to_string())
+        );
+    }
+
+    #[test]
+    fn test_load_pack_from_directory() {
+        let dir = std::env::temp_dir().join(format!("tonestep-voices-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), "do: do.wav\nsol: sol.wav\n").unwrap();
+        fs::write(dir.join("do.wav"), voice_fixture()).unwrap();
+        fs::write(dir.join("sol.wav"), voice_fixture()).unwrap();
+
+        let loaded = read_voice_pack_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let voices = loaded.unwrap();
+        let recorded_len = voices[&1].len();
+        let mut packs = VoicePacks::new();
+        packs.insert("solfege", voices);
+        packs.set_active("solfege").unwrap();
+
+        let bank = packs.voice_bank();
+        assert_eq!(bank.get(1).unwrap().len(), recorded_len);
+        assert_eq!(bank.get(8).unwrap().len(), recorded_len);
+        assert_ne!(bank.get(3).unwrap().len(), recorded_len);
+    }
+
+    #[test]
+    fn test_init_from_bytes_checks_every_file() {
+        assert!(init_voices_from_bytes(&[]).is_err());
+        assert!(init_voices_from_bytes(&vec![voice_fixture(); 13]).is_err());
+        assert_eq!(
+            init_voices_from_bytes(&[voice_fixture(), b"garbage".to_vec()]).err(),
+            Some("Voice for b2: Failed to open voice file: unknown format".to_string())
+        );
+    }
+
+    #[cfg(feature = "embedded-assets")]
+    #[test]
+    fn test_embedded_voices_match_resources() {
+        let voices = embedded_voices();
+        assert_eq!(voices.len(), ALL_NOTES.len());
+        for note in ALL_NOTES {
+            let key = note.to_keyboard_note();
+            let path = format!("{}/resources/{}.wav", env!("CARGO_MANIFEST_DIR"), key);
+            let expected = WavFile::new(&fs::read(path).unwrap()).unwrap();
+            assert_eq!(voices[&key].samples, expected.samples, "{}", note);
         }
     }
 }
@@ -0,0 +1,214 @@
+//! Golden audio regression tests.
+//!
+//! Renders fixed exercises without an audio device and compares snapshots of
+//! the output against the reference WAVs in `tests/golden`. After an
+//! intentional change to the sound, regenerate the references with
+//!
+//!     TONESTEP_REGENERATE_GOLDEN=1 cargo test --test golden
+//!
+//! and listen to the new files before committing them.
+
+use std::f32::consts::PI;
+use std::path::PathBuf;
+
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use rust_lib_tonestep::api::notes::Note;
+use rust_lib_tonestep::player::constants::{ROOT_END_TIME, SAMPLE_RATE};
+use rust_lib_tonestep::player::exercise_generator::ExerciseGenerator;
+use rust_lib_tonestep::player::wav::init_voices_from_dir;
+use rust_lib_tonestep::player::Player;
+
+const REGENERATE_VAR: &str = "TONESTEP_REGENERATE_GOLDEN";
+
+// One snapshot every second, taken half a second in to stay clear of the
+// timeline boundaries
+const WINDOW_FRAMES: usize = 2048;
+const WINDOW_OFFSET_SECONDS: f32 = 0.5;
+
+// Differences allowed against the reference
+const RMS_TOLERANCE: f32 = 0.05;
+const RMS_FLOOR: f32 = 1e-3;
+const PEAK_COUNT: usize = 3;
+const PEAK_MAX_FREQUENCY: f32 = 4000.0;
+const PEAK_FLOOR_DB: f32 = -40.0;
+const PEAK_BIN_TOLERANCE: usize = 1;
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.wav", name))
+}
+
+/// Renders one full exercise and returns its mono snapshots.
+fn render_snapshots(root: Note, relative: Note) -> Vec<Vec<f32>> {
+    init_voices_from_dir(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("resources")).unwrap();
+    let generator = ExerciseGenerator::with_exercise(root, relative, 1).unwrap();
+    let mut player = Player::from_generator(generator);
+
+    let frames = ROOT_END_TIME as usize * SAMPLE_RATE as usize;
+    let mut data = vec![0.0; frames * 2];
+    // Render in callback sized chunks like a device would
+    for chunk in data.chunks_mut(1024) {
+        player.render(chunk);
+    }
+
+    (0..ROOT_END_TIME as usize)
+        .map(|second| {
+            let start = ((second as f32 + WINDOW_OFFSET_SECONDS) * SAMPLE_RATE as f32) as usize;
+            data[start * 2..(start + WINDOW_FRAMES) * 2]
+                .chunks_exact(2)
+                .map(|frame| (frame[0] + frame[1]) * 0.5)
+                .collect()
+        })
+        .collect()
+}
+
+fn write_reference(name: &str, snapshots: &[Vec<f32>]) {
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+    };
+    let path = golden_path(name);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut writer = WavWriter::create(&path, spec).unwrap();
+    for sample in snapshots.iter().flatten() {
+        writer
+            .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16)
+            .unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+fn read_reference(name: &str) -> Vec<Vec<f32>> {
+    let path = golden_path(name);
+    let mut reader = WavReader::open(&path).unwrap_or_else(|e| {
+        panic!(
+            "missing reference {}: {}, run with {}=1 to create it",
+            path.display(),
+            e,
+            REGENERATE_VAR
+        )
+    });
+    let samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.unwrap() as f32 / i16::MAX as f32)
+        .collect();
+    samples
+        .chunks(WINDOW_FRAMES)
+        .map(|chunk| chunk.to_vec())
+        .collect()
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+fn rms_difference(a: &[f32], b: &[f32]) -> f32 {
+    let difference: Vec<f32> = a.iter().zip(b).map(|(x, y)| x - y).collect();
+    rms(&difference)
+}
+
+// Magnitude spectrum of a Hann windowed snapshot, up to PEAK_MAX_FREQUENCY
+fn spectrum(samples: &[f32]) -> Vec<f32> {
+    let n = samples.len();
+    let bins = (PEAK_MAX_FREQUENCY * n as f32 / SAMPLE_RATE as f32) as usize;
+    let windowed: Vec<f32> = samples
+        .iter()
+        .enumerate()
+        .map(|(i, s)| s * (0.5 - 0.5 * (2.0 * PI * i as f32 / n as f32).cos()))
+        .collect();
+
+    (0..bins)
+        .map(|k| {
+            let (mut re, mut im) = (0.0f32, 0.0f32);
+            for (i, s) in windowed.iter().enumerate() {
+                let angle = 2.0 * PI * (k * i % n) as f32 / n as f32;
+                re += s * angle.cos();
+                im -= s * angle.sin();
+            }
+            (re * re + im * im).sqrt()
+        })
+        .collect()
+}
+
+/// Bins of the strongest local maxima, ignoring anything far below the top.
+fn spectral_peaks(samples: &[f32]) -> Vec<usize> {
+    let spectrum = spectrum(samples);
+    let max = spectrum.iter().cloned().fold(0.0, f32::max);
+    if max <= 0.0 {
+        return vec![];
+    }
+    let floor = max * 10.0_f32.powf(PEAK_FLOOR_DB / 20.0);
+
+    let mut peaks: Vec<(usize, f32)> = (1..spectrum.len() - 1)
+        .filter(|&k| spectrum[k] >= floor)
+        .filter(|&k| spectrum[k] > spectrum[k - 1] && spectrum[k] >= spectrum[k + 1])
+        .map(|k| (k, spectrum[k]))
+        .collect();
+    peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+    peaks.truncate(PEAK_COUNT);
+    peaks.into_iter().map(|(k, _)| k).collect()
+}
+
+fn assert_matches_golden(name: &str, root: Note, relative: Note) {
+    let rendered = render_snapshots(root, relative);
+
+    if std::env::var(REGENERATE_VAR).is_ok() {
+        write_reference(name, &rendered);
+        return;
+    }
+
+    let reference = read_reference(name);
+    assert_eq!(
+        rendered.len(),
+        reference.len(),
+        "{}: number of snapshots changed",
+        name
+    );
+
+    for (second, (actual, expected)) in rendered.iter().zip(&reference).enumerate() {
+        let difference = rms_difference(actual, expected);
+        let allowed = rms(expected) * RMS_TOLERANCE + RMS_FLOOR;
+        assert!(
+            difference <= allowed,
+            "{} at {}.5s: RMS difference {} exceeds {}",
+            name,
+            second,
+            difference,
+            allowed
+        );
+
+        let actual_peaks = spectral_peaks(actual);
+        let expected_peaks = spectral_peaks(expected);
+        for peak in &expected_peaks {
+            assert!(
+                actual_peaks
+                    .iter()
+                    .any(|p| p.abs_diff(*peak) <= PEAK_BIN_TOLERANCE),
+                "{} at {}.5s: spectral peak at bin {} missing, got {:?}",
+                name,
+                second,
+                peak,
+                actual_peaks
+            );
+        }
+    }
+}
+
+#[test]
+fn test_golden_one_five() {
+    assert_matches_golden("one_five", Note::One, Note::Five);
+}
+
+#[test]
+fn test_golden_three_flat_three() {
+    assert_matches_golden("three_flat_three", Note::Three, Note::FlatThree);
+}
+
+#[test]
+fn test_golden_flat_six_seven() {
+    assert_matches_golden("flat_six_seven", Note::FlatSix, Note::Seven);
+}
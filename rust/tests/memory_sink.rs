@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use rust_lib_tonestep::api::notes::Note;
+use rust_lib_tonestep::player::constants::{ROOT_END_TIME, SAMPLE_RATE};
+use rust_lib_tonestep::player::manager;
+use rust_lib_tonestep::player::sink::MemorySink;
+
+fn peak(samples: &[f32]) -> f32 {
+    samples.iter().fold(0.0, |m, s| m.max(s.abs()))
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+// Sessions go through the global manager, so everything runs in one test
+#[test]
+fn test_session_renders_through_memory_sink() {
+    let sink = MemorySink::new();
+    manager::set_audio_sink(Box::new(sink.clone()));
+
+    assert!(!sink.is_playing());
+    assert_eq!(
+        peak(&sink.pull(1024)),
+        0.0,
+        "it should be silent before starting"
+    );
+
+    manager::start_playing(HashSet::from([Note::Five]));
+    assert!(sink.is_playing());
+
+    // First second, the drone fades in
+    let fade_in = sink.pull_seconds(1.0);
+    assert_eq!(fade_in.len(), 2 * SAMPLE_RATE as usize);
+    let start = &fade_in[..2 * 4800];
+    let end = &fade_in[fade_in.len() - 2 * 4800..];
+    assert!(rms(start) < rms(end), "the drone should fade in");
+
+    // Rest of the exercise plus the start of the next one
+    let session = sink.pull_seconds(ROOT_END_TIME as f32 + 2.0);
+    assert!(rms(&session) > 0.01, "it should produce audio");
+    assert!(peak(&session) <= 1.0, "it should never clip");
+    assert!(
+        session.chunks_exact(2).all(|frame| frame[0] == frame[1]),
+        "layers are centred by default"
+    );
+
+    manager::stop_playing();
+    assert!(!sink.is_playing());
+    assert_eq!(
+        peak(&sink.pull(1024)),
+        0.0,
+        "it should be silent after stopping"
+    );
+}